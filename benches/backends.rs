@@ -0,0 +1,55 @@
+//! Compares the vector and bitboard backends of `board::Game` by walking
+//! every legal 3x3 game through the trait.
+//!
+//! Run with `cargo bench --bench backends`.
+
+use analyzer::board::{self, bitboard, CellType, Coord, Game, GameOverType};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ROUNDS: u32 = 5;
+
+fn count_games(game_board: Box<dyn Game>, to_move: CellType) -> u64 {
+    if game_board.is_game_over() != GameOverType::PLAYING {
+        return 1;
+    }
+
+    let board_state = game_board.get_board_state();
+    let mut games = 0;
+    for position in 0..9 {
+        if board_state[position] != CellType::NON {
+            continue;
+        }
+
+        let coord = Coord {
+            x: (position % 3) as isize,
+            y: (position / 3) as isize,
+        };
+        games += count_games(game_board.set_player(coord, to_move), to_move.opponent());
+    }
+    return games;
+}
+
+fn bench(name: &str, new: fn() -> Box<dyn Game>) -> Duration {
+    let mut best = Duration::MAX;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        let games = count_games(black_box(new()), CellType::PLAYER_1);
+        best = best.min(start.elapsed());
+
+        assert_eq!(games, 255_168);
+    }
+
+    println!("{:<8} {:>10.2?} per full game-tree walk", name, best);
+    return best;
+}
+
+fn main() {
+    let vector_time = bench("vector", board::new);
+    let bitboard_time = bench("bitboard", bitboard::new);
+
+    println!(
+        "bitboard is {:.2}x the speed of vector",
+        vector_time.as_secs_f64() / bitboard_time.as_secs_f64()
+    );
+}
@@ -0,0 +1,207 @@
+//! Solves every position of the 3x3 game and writes the results to
+//! `$OUT_DIR/opening_book.rs`, which `analyzer::book` includes.
+//!
+//! A position is indexed by `sum(cell * 3^position)`, with an empty cell as 0,
+//! `PLAYER_1` as 1 and `PLAYER_2` as 2. Only the canonical orientation of a
+//! position, the one with the smallest index over all eight symmetries, has an
+//! entry. It holds one bitmask of best moves for each player, indexed by the
+//! canonical position; 0 when that player cannot be to move.
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+const SIZE: usize = 9;
+const POSITIONS: usize = 19683;
+
+const LINES: [[usize; 3]; 8] = [
+    [0, 1, 2],
+    [3, 4, 5],
+    [6, 7, 8],
+    [0, 3, 6],
+    [1, 4, 7],
+    [2, 5, 8],
+    [0, 4, 8],
+    [2, 4, 6],
+];
+
+/// Where a transform moves the cell at `(x, y)`.
+type Transform = fn(usize, usize) -> (usize, usize);
+
+/// Score of completing a line. Every ply before it costs one point, so that
+/// faster wins and slower losses score better.
+const SCORE_WIN: i32 = 100;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let symmetries = get_symmetries();
+    let mut solver = Solver {
+        scores: HashMap::new(),
+    };
+    let mut book = vec![[0u16; 2]; POSITIONS];
+
+    for first_player in [1, 2] {
+        fill_book(&mut solver, &symmetries, &mut book, [0; SIZE], first_player);
+    }
+
+    let mut source = String::new();
+    writeln!(source, "/// Where each position goes under each symmetry.").unwrap();
+    writeln!(
+        source,
+        "const SYMMETRIES: [[usize; {}]; 8] = {:?};",
+        SIZE, symmetries
+    )
+    .unwrap();
+    writeln!(source).unwrap();
+    writeln!(
+        source,
+        "/// Best moves of each player, by canonical position."
+    )
+    .unwrap();
+    writeln!(source, "static BOOK: [[u16; 2]; {}] = [", POSITIONS).unwrap();
+    for entry in &book {
+        writeln!(source, "    [{}, {}],", entry[0], entry[1]).unwrap();
+    }
+    writeln!(source, "];").unwrap();
+
+    let out_dir = env::var("OUT_DIR").expect("cargo sets OUT_DIR for build scripts");
+    fs::write(Path::new(&out_dir).join("opening_book.rs"), source).unwrap();
+}
+
+/// The eight rotations and reflections of the board, as the position each
+/// cell moves to.
+fn get_symmetries() -> [[usize; SIZE]; 8] {
+    let transforms: [Transform; 8] = [
+        |x, y| (x, y),
+        |x, y| (2 - y, x),
+        |x, y| (2 - x, 2 - y),
+        |x, y| (y, 2 - x),
+        |x, y| (2 - x, y),
+        |x, y| (x, 2 - y),
+        |x, y| (y, x),
+        |x, y| (2 - y, 2 - x),
+    ];
+
+    let mut symmetries = [[0; SIZE]; 8];
+    for (targets, transform) in symmetries.iter_mut().zip(transforms) {
+        for (position, target) in targets.iter_mut().enumerate() {
+            let (x, y) = transform(position % 3, position / 3);
+            *target = y * 3 + x;
+        }
+    }
+    return symmetries;
+}
+
+fn index_of(cells: &[u8; SIZE]) -> usize {
+    return cells
+        .iter()
+        .rev()
+        .fold(0, |index, &cell| index * 3 + cell as usize);
+}
+
+/// The cells of the canonical orientation of `cells`.
+fn canonical(symmetries: &[[usize; SIZE]; 8], cells: &[u8; SIZE]) -> [u8; SIZE] {
+    let mut smallest: Option<[u8; SIZE]> = None;
+
+    for symmetry in symmetries {
+        let mut transformed = [0; SIZE];
+        for (&target, &cell) in symmetry.iter().zip(cells) {
+            transformed[target] = cell;
+        }
+
+        match smallest {
+            Some(cells) if index_of(&cells) <= index_of(&transformed) => (),
+            _ => smallest = Some(transformed),
+        }
+    }
+
+    return smallest.expect("there are eight symmetries");
+}
+
+fn has_won(cells: &[u8; SIZE], player: u8) -> bool {
+    return LINES
+        .iter()
+        .any(|line| line.iter().all(|&position| cells[position] == player));
+}
+
+/// Visits every position reachable when `to_move` plays next and records its
+/// best moves.
+fn fill_book(
+    solver: &mut Solver,
+    symmetries: &[[usize; SIZE]; 8],
+    book: &mut [[u16; 2]],
+    cells: [u8; SIZE],
+    to_move: u8,
+) {
+    if has_won(&cells, 1) || has_won(&cells, 2) || !cells.contains(&0) {
+        return;
+    }
+
+    let canonical_cells = canonical(symmetries, &cells);
+    let entry = &mut book[index_of(&canonical_cells)][to_move as usize - 1];
+    if *entry != 0 {
+        return;
+    }
+    *entry = solver.best_moves(&canonical_cells, to_move);
+
+    for position in 0..SIZE {
+        if cells[position] == 0 {
+            let mut child = cells;
+            child[position] = to_move;
+            fill_book(solver, symmetries, book, child, 3 - to_move);
+        }
+    }
+}
+
+struct Solver {
+    scores: HashMap<([u8; SIZE], u8), i32>,
+}
+
+impl Solver {
+    /// Bitmask of every move of `to_move` that reaches the best score.
+    fn best_moves(&mut self, cells: &[u8; SIZE], to_move: u8) -> u16 {
+        let scores: Vec<(usize, i32)> = (0..SIZE)
+            .filter(|&position| cells[position] == 0)
+            .map(|position| (position, self.move_score(cells, position, to_move)))
+            .collect();
+        let best_score = scores.iter().map(|&(_, score)| score).max().unwrap();
+
+        return scores
+            .iter()
+            .filter(|&&(_, score)| score == best_score)
+            .fold(0, |moves, &(position, _)| moves | 1 << position);
+    }
+
+    fn move_score(&mut self, cells: &[u8; SIZE], position: usize, to_move: u8) -> i32 {
+        let mut child = *cells;
+        child[position] = to_move;
+
+        if has_won(&child, to_move) {
+            return SCORE_WIN;
+        }
+        if !child.contains(&0) {
+            return 0;
+        }
+
+        let reply_score = -self.score(&child, 3 - to_move);
+        return reply_score - reply_score.signum();
+    }
+
+    /// Score of the best move of `to_move`.
+    fn score(&mut self, cells: &[u8; SIZE], to_move: u8) -> i32 {
+        if let Some(&score) = self.scores.get(&(*cells, to_move)) {
+            return score;
+        }
+
+        let score = (0..SIZE)
+            .filter(|&position| cells[position] == 0)
+            .map(|position| self.move_score(cells, position, to_move))
+            .max()
+            .unwrap();
+        self.scores.insert((*cells, to_move), score);
+        return score;
+    }
+}
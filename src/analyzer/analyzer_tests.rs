@@ -1,7 +1,27 @@
 #[cfg(test)]
 mod analyzer_tests {
-    use crate::analyzer::{self, Analyzer};
-    use crate::board::{self, CellType, Coord};
+    use crate::analyzer::{self, Analyzer, Difficulty};
+    use crate::board::{self, Board, CellType, Coord, GameOverType};
+
+    /// Plays `count` marks of `cell_type` on the first cells outside `line`,
+    /// so that the position could come up in a real game.
+    fn add_marks_off_line(
+        game_board: Box<dyn board::Game>,
+        line: [Coord; 3],
+        cell_type: CellType,
+        count: usize,
+    ) -> Box<dyn board::Game> {
+        let board_state = game_board.get_board_state();
+        let coords: Vec<Coord> = (0..board_state.len())
+            .map(|position| board_state.coord_of(position))
+            .filter(|coord| !line.contains(coord))
+            .take(count)
+            .collect();
+
+        return coords.into_iter().fold(game_board, |game_board, coord| {
+            game_board.set_player(coord, cell_type)
+        });
+    }
 
     struct TwoPositionWinningTest {
         position_1: Coord,
@@ -15,14 +35,17 @@ mod analyzer_tests {
         $(
             #[test]
             fn $name() {
-                let game_analyzer = analyzer::new();
+                let game_analyzer = analyzer::new(Difficulty::PERFECT);
                 let game_board = board::new();
                 let test_data: TwoPositionWinningTest = $value;
 
+                let game_board = game_board
+                    .set_player(test_data.position_1, test_data.player_cell_type)
+                    .set_player(test_data.position_2, test_data.player_cell_type);
+                let line = [test_data.position_1, test_data.position_2, test_data.expected_position_to_play];
+
                 let best_move = game_analyzer.get_best_move(
-                    game_board
-                        .set_player(test_data.position_1, test_data.player_cell_type)
-                        .set_player(test_data.position_2, test_data.player_cell_type),
+                    add_marks_off_line(game_board, line, test_data.player_cell_type.opponent(), 2),
                     test_data.player_cell_type
                 );
 
@@ -215,14 +238,17 @@ mod analyzer_tests {
         $(
             #[test]
             fn $name() {
-                let game_analyzer = analyzer::new();
+                let game_analyzer = analyzer::new(Difficulty::PERFECT);
                 let game_board = board::new();
                 let test_data: TwoPositionDefendingTest = $value;
 
+                let game_board = game_board
+                    .set_player(test_data.position_1, test_data.opponent_cell_type)
+                    .set_player(test_data.position_2, test_data.opponent_cell_type);
+                let line = [test_data.position_1, test_data.position_2, test_data.expected_position_to_play];
+
                 let best_move = game_analyzer.get_best_move(
-                    game_board
-                        .set_player(test_data.position_1, test_data.opponent_cell_type)
-                        .set_player(test_data.position_2, test_data.opponent_cell_type),
+                    add_marks_off_line(game_board, line, test_data.player_cell_type, 1),
                     test_data.player_cell_type
                 );
 
@@ -425,4 +451,339 @@ mod analyzer_tests {
             expected_position_to_play: Coord { x: 0, y: 2 }
         },
     }
+
+    struct LineMoveTest {
+        board: &'static str,
+        player_cell_type: CellType,
+        expected_position_to_play: Coord,
+    }
+
+    macro_rules! line_move_table_tests {
+        ( $( $name:ident: $value:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let test_data: LineMoveTest = $value;
+                let game_board: Board = test_data.board.parse().unwrap();
+
+                let position = analyzer::get_line_move(&game_board, test_data.player_cell_type);
+
+                assert_eq!(
+                    position.map(|position| game_board.coord_of(position)),
+                    Some(test_data.expected_position_to_play)
+                );
+            }
+        )*
+        };
+    }
+
+    line_move_table_tests! {
+        given_block_on_earlier_row_then_return_winning_coordinate: LineMoveTest {
+            board: "OO./XX./...",
+            player_cell_type: CellType::PLAYER_1,
+            expected_position_to_play: Coord { x: 2, y: 1 }
+        },
+
+        given_block_on_row_and_win_on_diagonal_then_return_winning_coordinate: LineMoveTest {
+            board: "..OO/.X../..X./.... 3",
+            player_cell_type: CellType::PLAYER_1,
+            expected_position_to_play: Coord { x: 0, y: 0 }
+        },
+
+        given_single_block_then_return_blocking_coordinate: LineMoveTest {
+            board: "XX./.O./...",
+            player_cell_type: CellType::PLAYER_2,
+            expected_position_to_play: Coord { x: 2, y: 0 }
+        },
+
+        given_two_blocks_then_return_block_leaving_winning_coordinate: LineMoveTest {
+            board: "OO./X../XXO",
+            player_cell_type: CellType::PLAYER_1,
+            expected_position_to_play: Coord { x: 1, y: 1 }
+        },
+
+        given_two_blocks_then_return_block_closing_most_lines: LineMoveTest {
+            board: "XOXX/O.../OO../XOX. 3",
+            player_cell_type: CellType::PLAYER_1,
+            expected_position_to_play: Coord { x: 1, y: 1 }
+        },
+    }
+
+    #[test]
+    fn given_win_and_block_on_different_lines_then_return_winning_coordinate() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+        let game_board: Board = "OO./XX./...".parse().unwrap();
+
+        let best_move = game_analyzer.get_best_move(Box::new(game_board), CellType::PLAYER_1);
+
+        assert_eq!(best_move, Some(Coord { x: 2, y: 1 }));
+    }
+
+    fn assert_never_loses(
+        game_board: Box<dyn board::Game>,
+        to_move: CellType,
+        analyzer_cell_type: CellType,
+    ) {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+
+        match game_board.is_game_over() {
+            GameOverType::PLAYING => (),
+            GameOverType::PLAYER_1_WIN => {
+                assert_eq!(analyzer_cell_type, CellType::PLAYER_1);
+                return;
+            }
+            GameOverType::PLAYER_2_WIN => {
+                assert_eq!(analyzer_cell_type, CellType::PLAYER_2);
+                return;
+            }
+            GameOverType::DRAW => return,
+        }
+
+        let next_to_move = match to_move {
+            CellType::PLAYER_1 => CellType::PLAYER_2,
+            _ => CellType::PLAYER_1,
+        };
+
+        if to_move == analyzer_cell_type {
+            let board_state = game_board.get_board_state();
+            let best_move = game_analyzer
+                .get_best_move(Box::new(board_state.clone()), to_move)
+                .expect("analyzer should always find a move while the game is playing");
+            let position = (best_move.y * 3 + best_move.x) as usize;
+
+            assert_eq!(board_state[position], CellType::NON);
+            assert_never_loses(
+                game_board.set_player(best_move, to_move),
+                next_to_move,
+                analyzer_cell_type,
+            );
+            return;
+        }
+
+        for position in 0..9 {
+            if game_board.get_board_state()[position] != CellType::NON {
+                continue;
+            }
+
+            let coord = Coord {
+                x: (position % 3) as isize,
+                y: (position / 3) as isize,
+            };
+            assert_never_loses(
+                game_board.set_player(coord, to_move),
+                next_to_move,
+                analyzer_cell_type,
+            );
+        }
+    }
+
+    #[test]
+    fn given_empty_board_then_return_a_move() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+
+        let best_move = game_analyzer.get_best_move(board::new(), CellType::PLAYER_1);
+
+        assert!(best_move.is_some());
+    }
+
+    #[test]
+    fn given_finished_game_then_return_no_move() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+        let game_board = board::new()
+            .set_player(Coord { x: 0, y: 0 }, CellType::PLAYER_1)
+            .set_player(Coord { x: 0, y: 1 }, CellType::PLAYER_2)
+            .set_player(Coord { x: 1, y: 0 }, CellType::PLAYER_1)
+            .set_player(Coord { x: 1, y: 1 }, CellType::PLAYER_2)
+            .set_player(Coord { x: 2, y: 0 }, CellType::PLAYER_1);
+
+        let best_move = game_analyzer.get_best_move(game_board, CellType::PLAYER_2);
+
+        assert_eq!(best_move, None);
+    }
+
+    #[test]
+    fn given_illegal_position_then_return_no_move() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+
+        for (notation, player_cell_type) in [
+            ("XX./.../...", CellType::PLAYER_2),
+            ("XX./O../...", CellType::PLAYER_1),
+            ("XX./OO./...", CellType::NON),
+            ("XXX/OOO/...", CellType::PLAYER_1),
+        ] {
+            let game_board: Board = notation.parse().unwrap();
+
+            let best_move = game_analyzer.get_best_move(Box::new(game_board), player_cell_type);
+
+            assert_eq!(best_move, None, "{}", notation);
+        }
+
+        let mut game_board = board::new().get_board_state();
+        game_board.cells.truncate(5);
+        assert_eq!(
+            game_analyzer.get_best_move(Box::new(game_board), CellType::PLAYER_1),
+            None
+        );
+    }
+
+    #[test]
+    fn given_player_1_analyzer_then_never_lose_against_any_replies() {
+        assert_never_loses(board::new(), CellType::PLAYER_1, CellType::PLAYER_1);
+    }
+
+    #[test]
+    fn given_player_2_analyzer_then_never_lose_against_any_replies() {
+        assert_never_loses(board::new(), CellType::PLAYER_1, CellType::PLAYER_2);
+    }
+
+    #[test]
+    fn given_larger_board_then_return_winning_coordinate() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+        let game_board = board::new_with_size(4, 4, 3)
+            .set_player(Coord { x: 1, y: 1 }, CellType::PLAYER_1)
+            .set_player(Coord { x: 1, y: 0 }, CellType::PLAYER_2)
+            .set_player(Coord { x: 1, y: 2 }, CellType::PLAYER_1)
+            .set_player(Coord { x: 3, y: 3 }, CellType::PLAYER_2);
+
+        let best_move = game_analyzer.get_best_move(game_board, CellType::PLAYER_1);
+
+        assert_eq!(best_move, Some(Coord { x: 1, y: 3 }));
+    }
+
+    #[test]
+    fn given_larger_board_then_return_defending_coordinate() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+        let game_board = board::new_with_size(5, 2, 4)
+            .set_player(Coord { x: 0, y: 0 }, CellType::PLAYER_2)
+            .set_player(Coord { x: 0, y: 1 }, CellType::PLAYER_1)
+            .set_player(Coord { x: 1, y: 0 }, CellType::PLAYER_2)
+            .set_player(Coord { x: 1, y: 1 }, CellType::PLAYER_1)
+            .set_player(Coord { x: 2, y: 0 }, CellType::PLAYER_2);
+
+        let best_move = game_analyzer.get_best_move(game_board, CellType::PLAYER_1);
+
+        assert_eq!(best_move, Some(Coord { x: 3, y: 0 }));
+    }
+
+    fn count_best_moves(difficulty: Difficulty, seed: u64) -> usize {
+        let game_analyzer = analyzer::new_with_seed(difficulty, seed);
+        let mut best_move_count = 0;
+
+        for _ in 0..200 {
+            let game_board = board::new()
+                .set_player(Coord { x: 0, y: 0 }, CellType::PLAYER_2)
+                .set_player(Coord { x: 1, y: 1 }, CellType::PLAYER_1)
+                .set_player(Coord { x: 1, y: 0 }, CellType::PLAYER_2);
+
+            let best_move = game_analyzer.get_best_move(game_board, CellType::PLAYER_1);
+            if best_move == Some(Coord { x: 2, y: 0 }) {
+                best_move_count += 1;
+            }
+        }
+
+        return best_move_count;
+    }
+
+    #[test]
+    fn given_same_seed_then_return_same_moves() {
+        let first_analyzer = analyzer::new_with_seed(Difficulty::EASY, 11);
+        let second_analyzer = analyzer::new_with_seed(Difficulty::EASY, 11);
+
+        for _ in 0..50 {
+            assert_eq!(
+                first_analyzer.get_best_move(board::new(), CellType::PLAYER_1),
+                second_analyzer.get_best_move(board::new(), CellType::PLAYER_1)
+            );
+        }
+    }
+
+    #[test]
+    fn given_random_difficulty_then_return_legal_moves() {
+        let game_analyzer = analyzer::new_with_seed(Difficulty::RANDOM, 3);
+        let game_board = board::new()
+            .set_player(Coord { x: 0, y: 0 }, CellType::PLAYER_1)
+            .set_player(Coord { x: 1, y: 1 }, CellType::PLAYER_2);
+        let board_state = game_board.get_board_state();
+
+        for _ in 0..50 {
+            let best_move = game_analyzer
+                .get_best_move(Box::new(board_state.clone()), CellType::PLAYER_1)
+                .unwrap();
+
+            assert_eq!(
+                board_state[board_state.position_of(&best_move).unwrap()],
+                CellType::NON
+            );
+        }
+    }
+
+    #[test]
+    fn given_higher_difficulty_then_play_best_move_more_often() {
+        let random_count = count_best_moves(Difficulty::RANDOM, 5);
+        let easy_count = count_best_moves(Difficulty::EASY, 5);
+        let medium_count = count_best_moves(Difficulty::MEDIUM, 5);
+        let perfect_count = count_best_moves(Difficulty::PERFECT, 5);
+
+        assert!(random_count < easy_count);
+        assert!(easy_count < medium_count);
+        assert!(medium_count < perfect_count);
+        assert_eq!(perfect_count, 200);
+    }
+
+    #[cfg(feature = "parallel")]
+    fn assert_parallel_search_matches(
+        board_state: &mut Board,
+        to_move: CellType,
+        analyzers: &[analyzer::AnalyzerState; 2],
+        visited: &mut std::collections::HashSet<(String, i8)>,
+    ) -> usize {
+        if board::Game::is_game_over(board_state) != GameOverType::PLAYING
+            || !visited.insert((board_state.to_string(), to_move as i8))
+        {
+            return 0;
+        }
+
+        let [single_thread, parallel] = analyzers;
+        assert_eq!(
+            parallel.get_best_move(Box::new(board_state.clone()), to_move),
+            single_thread.get_best_move(Box::new(board_state.clone()), to_move),
+            "{}",
+            board_state
+        );
+        assert_eq!(
+            parallel.score_moves(Box::new(board_state.clone()), to_move),
+            single_thread.score_moves(Box::new(board_state.clone()), to_move),
+            "{}",
+            board_state
+        );
+
+        let mut positions = 1;
+        for position in analyzer::get_ordered_moves(board_state, to_move) {
+            board_state[position] = to_move;
+            positions +=
+                assert_parallel_search_matches(board_state, to_move.opponent(), analyzers, visited);
+            board_state[position] = CellType::NON;
+        }
+        return positions;
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn given_parallel_search_then_play_every_position_as_on_one_thread() {
+        let mut single_thread = analyzer::new(Difficulty::PERFECT);
+        single_thread.set_threads(1);
+        let mut parallel = analyzer::new(Difficulty::PERFECT);
+        parallel.set_threads(4);
+        let analyzers = [single_thread, parallel];
+        let mut visited = std::collections::HashSet::new();
+        let mut positions = 0;
+
+        for to_move in [CellType::PLAYER_1, CellType::PLAYER_2] {
+            let mut board_state = Board::new(3, 3, 3);
+            positions +=
+                assert_parallel_search_matches(&mut board_state, to_move, &analyzers, &mut visited);
+        }
+
+        assert_eq!(positions, 9040);
+    }
 }
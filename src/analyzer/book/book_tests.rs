@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod book_tests {
+    use crate::analyzer::{self, book, Analyzer, Difficulty};
+    use crate::board::{self, CellType, Coord, Game, GameOverType};
+    use std::collections::HashSet;
+
+    #[test]
+    fn given_winning_line_then_return_winning_coordinate() {
+        let game_board: Box<dyn Game> = Box::new("OO./XX./X..".parse::<board::Board>().unwrap());
+
+        let best_move = book::new().get_best_move(game_board, CellType::PLAYER_2);
+
+        assert_eq!(best_move, Some(Coord { x: 2, y: 0 }));
+    }
+
+    #[test]
+    fn given_empty_board_then_every_move_draws() {
+        let best_moves = book::new().best_moves(board::new(), CellType::PLAYER_1);
+
+        assert_eq!(best_moves.len(), 9);
+    }
+
+    #[test]
+    fn given_larger_board_then_return_no_move() {
+        let best_move =
+            book::new().get_best_move(board::new_with_size(4, 4, 3), CellType::PLAYER_1);
+
+        assert_eq!(best_move, None);
+    }
+
+    #[test]
+    fn given_finished_game_then_return_no_move() {
+        let game_board: Box<dyn Game> = Box::new("XXX/OO./...".parse::<board::Board>().unwrap());
+
+        let best_move = book::new().get_best_move(game_board, CellType::PLAYER_2);
+
+        assert_eq!(best_move, None);
+    }
+
+    fn assert_book_matches_search(
+        game_board: Box<dyn Game>,
+        to_move: CellType,
+        game_analyzer: &analyzer::AnalyzerState,
+        visited: &mut HashSet<String>,
+    ) {
+        let board_state = game_board.get_board_state();
+        if game_board.is_game_over() != GameOverType::PLAYING
+            || !visited.insert(format!("{} {:?}", board_state, to_move))
+        {
+            return;
+        }
+
+        let scores = game_analyzer.score_moves(Box::new(board_state.clone()), to_move);
+        let best_score = scores
+            .iter()
+            .map(|(_, score)| *score)
+            .fold(
+                scores[0].1,
+                |best, score| {
+                    if score > best {
+                        score
+                    } else {
+                        best
+                    }
+                },
+            );
+        let search_best_moves: Vec<Coord> = scores
+            .iter()
+            .filter(|(_, score)| *score == best_score)
+            .map(|(coord, _)| *coord)
+            .collect();
+
+        let best_moves = book::new().best_moves(Box::new(board_state.clone()), to_move);
+        assert_eq!(
+            best_moves, search_best_moves,
+            "{} {:?}",
+            board_state, to_move
+        );
+
+        for (coord, _) in scores {
+            assert_book_matches_search(
+                game_board.set_player(coord, to_move),
+                to_move.opponent(),
+                game_analyzer,
+                visited,
+            );
+        }
+    }
+
+    #[test]
+    fn should_agree_with_search_on_every_reachable_position() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+        let mut visited = HashSet::new();
+
+        assert_book_matches_search(
+            board::new(),
+            CellType::PLAYER_1,
+            &game_analyzer,
+            &mut visited,
+        );
+        assert_book_matches_search(
+            board::new(),
+            CellType::PLAYER_2,
+            &game_analyzer,
+            &mut visited,
+        );
+
+        assert_eq!(visited.len(), 2 * 4520);
+    }
+}
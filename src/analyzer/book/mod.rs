@@ -0,0 +1,91 @@
+mod book_tests;
+
+use crate::analyzer::{is_playable, Analyzer};
+use crate::board::{self, Board, CellType, Coord};
+
+// Defines `SYMMETRIES` and `BOOK`, see `build.rs` for how they are laid out.
+include!(concat!(env!("OUT_DIR"), "/opening_book.rs"));
+
+/// Analyzer for the 3x3 game that looks its moves up in a table of every
+/// position, solved when the crate is built. Other boards get no move.
+pub struct BookAnalyzer {}
+
+impl BookAnalyzer {
+    /// Every move that gives `for_cell_type` the best result against perfect
+    /// play, in board order. Empty when the board is not 3x3 with three in a
+    /// row, the game is over or `for_cell_type` cannot be the one to move.
+    pub fn best_moves(
+        &self,
+        game_board: Box<dyn board::Game>,
+        for_cell_type: CellType,
+    ) -> Vec<Coord> {
+        let board_state = game_board.get_board_state();
+        if !is_in_book(&board_state, for_cell_type) {
+            return vec![];
+        }
+
+        let (index, symmetry) = canonical_index(&board_state);
+        let moves = BOOK[index][player_index(for_cell_type)];
+
+        return (0..board_state.len())
+            .filter(|&position| moves & 1 << SYMMETRIES[symmetry][position] != 0)
+            .map(|position| board_state.coord_of(position))
+            .collect();
+    }
+}
+
+impl Analyzer for BookAnalyzer {
+    fn get_best_move(
+        &self,
+        game_board: Box<dyn board::Game>,
+        for_cell_type: CellType,
+    ) -> Option<Coord> {
+        return self.best_moves(game_board, for_cell_type).first().copied();
+    }
+}
+
+pub fn new() -> BookAnalyzer {
+    return BookAnalyzer {};
+}
+
+fn is_in_book(board: &Board, for_cell_type: CellType) -> bool {
+    if board.width != 3 || board.height != 3 || board.win_length != 3 {
+        return false;
+    }
+    return is_playable(board, for_cell_type);
+}
+
+fn player_index(cell_type: CellType) -> usize {
+    match cell_type {
+        CellType::PLAYER_2 => return 1,
+        _ => return 0,
+    }
+}
+
+fn cell_digit(cell_type: CellType) -> usize {
+    match cell_type {
+        CellType::NON => return 0,
+        CellType::PLAYER_1 => return 1,
+        CellType::PLAYER_2 => return 2,
+    }
+}
+
+/// The book index of the canonical orientation of `board`, together with the
+/// symmetry that produces it.
+fn canonical_index(board: &Board) -> (usize, usize) {
+    let mut canonical = (usize::MAX, 0);
+
+    for (symmetry, targets) in SYMMETRIES.iter().enumerate() {
+        let index = board
+            .iter()
+            .zip(targets)
+            .map(|(&cell, &target)| cell_digit(cell) * 3usize.pow(target as u32))
+            .sum();
+
+        if index < canonical.0 {
+            canonical = (index, symmetry);
+        }
+    }
+
+    return canonical;
+}
@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod explanation_tests {
+    use crate::analyzer::explanation::{classify_move, MoveExplanation, MoveKind};
+    use crate::analyzer::limits::SearchLimits;
+    use crate::analyzer::{self, Analyzer, Difficulty};
+    use crate::board::{self, Board, CellType, Coord, GameState};
+
+    struct ClassifyTest {
+        board: &'static str,
+        coord: Coord,
+        cell_type: CellType,
+        expected_kind: MoveKind,
+    }
+
+    macro_rules! classify_move_table_tests {
+        ( $( $name:ident: $value:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let test_data: ClassifyTest = $value;
+                let board: Board = test_data.board.parse().unwrap();
+                let position = board.position_of(&test_data.coord).unwrap();
+
+                let kind = classify_move(&board, position, test_data.cell_type);
+
+                assert_eq!(kind, test_data.expected_kind);
+            }
+        )*
+        };
+    }
+
+    classify_move_table_tests! {
+        given_completed_line_then_classify_as_immediate_win: ClassifyTest {
+            board: "XX./OO./...",
+            coord: Coord { x: 2, y: 0 },
+            cell_type: CellType::PLAYER_1,
+            expected_kind: MoveKind::IMMEDIATE_WIN
+        },
+
+        given_opponent_line_then_classify_as_block: ClassifyTest {
+            board: "XX./O../...",
+            coord: Coord { x: 2, y: 0 },
+            cell_type: CellType::PLAYER_2,
+            expected_kind: MoveKind::BLOCK
+        },
+
+        given_two_new_threats_then_classify_as_fork: ClassifyTest {
+            board: "X../.O./..X",
+            coord: Coord { x: 2, y: 0 },
+            cell_type: CellType::PLAYER_1,
+            expected_kind: MoveKind::FORK
+        },
+
+        given_forcing_side_move_then_classify_as_block_fork: ClassifyTest {
+            board: "X../.O./..X",
+            coord: Coord { x: 1, y: 0 },
+            cell_type: CellType::PLAYER_2,
+            expected_kind: MoveKind::BLOCK_FORK
+        },
+
+        given_empty_center_then_classify_as_center: ClassifyTest {
+            board: ".../.../...",
+            coord: Coord { x: 1, y: 1 },
+            cell_type: CellType::PLAYER_1,
+            expected_kind: MoveKind::CENTER
+        },
+
+        given_empty_corner_then_classify_as_corner: ClassifyTest {
+            board: ".../.X./...",
+            coord: Coord { x: 2, y: 2 },
+            cell_type: CellType::PLAYER_2,
+            expected_kind: MoveKind::CORNER
+        },
+
+        given_quiet_side_move_then_classify_as_search: ClassifyTest {
+            board: ".../.X./...",
+            coord: Coord { x: 1, y: 0 },
+            cell_type: CellType::PLAYER_2,
+            expected_kind: MoveKind::SEARCH
+        },
+    }
+
+    #[test]
+    fn should_explain_winning_move_with_score_and_line() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+        let game_state: GameState = "XX./OO./... x".parse().unwrap();
+
+        let explanation = game_analyzer
+            .explain_move(Box::new(game_state), CellType::PLAYER_1)
+            .unwrap();
+
+        assert_eq!(
+            explanation,
+            MoveExplanation {
+                coord: Coord { x: 2, y: 0 },
+                cell_type: CellType::PLAYER_1,
+                kind: MoveKind::IMMEDIATE_WIN,
+                principal_variation: vec![Coord { x: 2, y: 0 }],
+                score: Some(999),
+            }
+        );
+        assert_eq!(
+            explanation.to_string(),
+            "X plays (2, 0), which wins the game. X can force a win in 1 move."
+        );
+    }
+
+    #[test]
+    fn should_explain_drawing_move_with_full_principal_variation() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+        let game_state: GameState = ".../.../... x".parse().unwrap();
+
+        let explanation = game_analyzer
+            .explain_move(Box::new(game_state.clone()), CellType::PLAYER_1)
+            .unwrap();
+
+        assert_eq!(explanation.score, Some(0));
+        assert_eq!(explanation.principal_variation.len(), 9);
+        assert_eq!(
+            Some(explanation.coord),
+            game_analyzer.get_best_move(Box::new(game_state), CellType::PLAYER_1)
+        );
+        assert!(explanation
+            .to_string()
+            .contains("With best play the game is a draw. Expected line: "));
+    }
+
+    #[test]
+    fn should_explain_lost_position_from_point_of_view_of_player() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+        let game_state: GameState = "X../.O./..X o".parse().unwrap();
+        let lost_state: GameState = "X.X/.O./O.X o".parse().unwrap();
+
+        let explanation = game_analyzer
+            .explain_move(Box::new(game_state), CellType::PLAYER_2)
+            .unwrap();
+        let lost_explanation = game_analyzer
+            .explain_move(Box::new(lost_state), CellType::PLAYER_2)
+            .unwrap();
+
+        assert_eq!(explanation.kind, MoveKind::BLOCK_FORK);
+        assert_eq!(explanation.score, Some(0));
+        assert!(lost_explanation.score.unwrap() < 0);
+        assert!(lost_explanation
+            .to_string()
+            .contains("X can force a win in 1 move. Expected line: "));
+    }
+
+    #[test]
+    fn should_explain_random_move_on_large_board_without_searching() {
+        let game_analyzer = analyzer::new(Difficulty::RANDOM);
+
+        let explanation = game_analyzer
+            .explain_move(board::new_with_size(7, 7, 5), CellType::PLAYER_1)
+            .unwrap();
+
+        assert_eq!(explanation.score, None);
+        assert_eq!(explanation.principal_variation, vec![explanation.coord]);
+    }
+
+    #[test]
+    fn should_stop_explaining_when_limits_run_out() {
+        let mut game_analyzer = analyzer::new(Difficulty::PERFECT);
+        game_analyzer.set_limits(SearchLimits {
+            max_nodes: Some(2000),
+            ..SearchLimits::default()
+        });
+
+        let explanation = game_analyzer
+            .explain_move(board::new_with_size(7, 7, 5), CellType::PLAYER_1)
+            .unwrap();
+
+        assert_eq!(explanation.score, None);
+        assert_eq!(explanation.principal_variation, vec![explanation.coord]);
+        assert!(explanation.to_string().ends_with("."));
+    }
+
+    #[test]
+    fn should_explain_within_limits_that_are_not_reached() {
+        let mut game_analyzer = analyzer::new(Difficulty::PERFECT);
+        game_analyzer.set_limits(SearchLimits {
+            max_nodes: Some(1_000_000),
+            ..SearchLimits::default()
+        });
+        let game_state: GameState = ".../.../... x".parse().unwrap();
+
+        let explanation = game_analyzer
+            .explain_move(Box::new(game_state), CellType::PLAYER_1)
+            .unwrap();
+
+        assert_eq!(explanation.score, Some(0));
+        assert_eq!(explanation.principal_variation.len(), 9);
+    }
+}
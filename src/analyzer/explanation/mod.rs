@@ -0,0 +1,137 @@
+mod explanation_tests;
+
+use crate::analyzer::score::{MoveScore, Outcome};
+use crate::analyzer::{get_fork_positions, get_winning_positions};
+use crate::board::{Board, CellType, Coord};
+use std::fmt;
+
+/// Why a move was chosen, checked in this order: a move that wins at once is
+/// an `IMMEDIATE_WIN` even if it also blocks.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MoveKind {
+    IMMEDIATE_WIN,
+    BLOCK,
+    FORK,
+    BLOCK_FORK,
+    CENTER,
+    CORNER,
+    SEARCH,
+}
+
+impl fmt::Display for MoveKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            MoveKind::IMMEDIATE_WIN => "wins the game",
+            MoveKind::BLOCK => "blocks a line the opponent was about to complete",
+            MoveKind::FORK => "creates two threats at once",
+            MoveKind::BLOCK_FORK => "stops the opponent from creating two threats at once",
+            MoveKind::CENTER => "takes the center",
+            MoveKind::CORNER => "takes a corner",
+            MoveKind::SEARCH => "is the strongest move found by looking ahead",
+        };
+        return write!(f, "{}", description);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoveExplanation {
+    pub coord: Coord,
+    pub cell_type: CellType,
+    pub kind: MoveKind,
+    /// The move followed by the best replies of both sides, as far as the
+    /// search limits allow.
+    pub principal_variation: Vec<Coord>,
+    /// Search score of the move for `cell_type`: positive wins, negative
+    /// loses, zero draws. `None` when the move was picked without searching
+    /// or the search limits ran out before the score was known.
+    pub score: Option<i32>,
+}
+
+impl fmt::Display for MoveExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let player = self.cell_type;
+        write!(
+            f,
+            "{} plays ({}, {}), which {}.",
+            player, self.coord.x, self.coord.y, self.kind
+        )?;
+
+        if let Some(score) = self.score {
+            let move_score = MoveScore::from_score(score);
+            match move_score.outcome {
+                Outcome::WIN => write!(
+                    f,
+                    " {} can force a win in {}.",
+                    player,
+                    count_moves(move_score.plies.div_ceil(2))
+                )?,
+                Outcome::LOSS => write!(
+                    f,
+                    " {} can force a win in {}.",
+                    self.cell_type.opponent(),
+                    count_moves(move_score.plies / 2)
+                )?,
+                Outcome::DRAW => write!(f, " With best play the game is a draw.")?,
+            }
+        }
+
+        if self.principal_variation.len() > 1 {
+            let moves: Vec<String> = self
+                .principal_variation
+                .iter()
+                .map(|coord| format!("({}, {})", coord.x, coord.y))
+                .collect();
+            write!(f, " Expected line: {}.", moves.join(" "))?;
+        }
+
+        return Ok(());
+    }
+}
+
+/// Classifies playing `position` on `board` for `cell_type`.
+pub fn classify_move(board: &Board, position: usize, cell_type: CellType) -> MoveKind {
+    let opponent = cell_type.opponent();
+
+    if get_winning_positions(board, cell_type).contains(&position) {
+        return MoveKind::IMMEDIATE_WIN;
+    }
+    if get_winning_positions(board, opponent).contains(&position) {
+        return MoveKind::BLOCK;
+    }
+
+    let mut board_after = board.clone();
+    board_after[position] = cell_type;
+    let threats = get_winning_positions(&board_after, cell_type);
+    if threats.len() >= 2 {
+        return MoveKind::FORK;
+    }
+
+    if !get_fork_positions(board, opponent).is_empty() {
+        // The opponent fork is stopped either by taking its square or by a
+        // threat whose forced answer is not a fork square.
+        let remaining_forks = get_fork_positions(&board_after, opponent);
+        let forces_safe_reply = threats.len() == 1 && !remaining_forks.contains(&threats[0]);
+        if remaining_forks.is_empty() || forces_safe_reply {
+            return MoveKind::BLOCK_FORK;
+        }
+    }
+
+    let Coord { x, y } = board.coord_of(position);
+    let (max_x, max_y) = (board.width as isize - 1, board.height as isize - 1);
+    if board.width % 2 == 1 && board.height % 2 == 1 && x * 2 == max_x && y * 2 == max_y {
+        return MoveKind::CENTER;
+    }
+    if (x == 0 || x == max_x) && (y == 0 || y == max_y) {
+        return MoveKind::CORNER;
+    }
+
+    return MoveKind::SEARCH;
+}
+
+fn count_moves(moves: u32) -> String {
+    match moves {
+        1 => return String::from("1 move"),
+        _ => return format!("{} moves", moves),
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod heuristic_tests {
+    use crate::analyzer::{heuristic, Analyzer};
+    use crate::board::{self, Board, CellType, Coord, GameOverType};
+
+    struct RuleTest {
+        board: &'static str,
+        player_cell_type: CellType,
+        expected_position_to_play: Coord,
+    }
+
+    macro_rules! rule_table_tests {
+        ( $( $name:ident: $value:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let game_analyzer = heuristic::new();
+                let test_data: RuleTest = $value;
+                let game_board: Board = test_data.board.parse().unwrap();
+
+                let best_move = game_analyzer.get_best_move(
+                    Box::new(game_board),
+                    test_data.player_cell_type
+                );
+
+                assert_eq!(best_move, Some(test_data.expected_position_to_play));
+            }
+        )*
+        };
+    }
+
+    rule_table_tests! {
+        given_winning_line_then_return_winning_coordinate: RuleTest {
+            board: "OO./XX./X..",
+            player_cell_type: CellType::PLAYER_2,
+            expected_position_to_play: Coord { x: 2, y: 0 }
+        },
+
+        given_opponent_line_then_return_blocking_coordinate: RuleTest {
+            board: "X../XO./...",
+            player_cell_type: CellType::PLAYER_2,
+            expected_position_to_play: Coord { x: 0, y: 2 }
+        },
+
+        given_win_and_block_then_return_winning_coordinate: RuleTest {
+            board: "XX./.../OO.",
+            player_cell_type: CellType::PLAYER_2,
+            expected_position_to_play: Coord { x: 2, y: 2 }
+        },
+
+        given_fork_available_then_return_forking_coordinate: RuleTest {
+            board: "X.O/.../O.X",
+            player_cell_type: CellType::PLAYER_1,
+            expected_position_to_play: Coord { x: 1, y: 1 }
+        },
+
+        given_single_opponent_fork_then_take_fork_coordinate: RuleTest {
+            board: "X../.O./.X.",
+            player_cell_type: CellType::PLAYER_2,
+            expected_position_to_play: Coord { x: 0, y: 2 }
+        },
+
+        given_opposite_corner_forks_then_force_with_side: RuleTest {
+            board: "X../.O./..X",
+            player_cell_type: CellType::PLAYER_2,
+            expected_position_to_play: Coord { x: 1, y: 0 }
+        },
+
+        given_adjacent_edge_forks_then_block_fork: RuleTest {
+            board: ".X./X../.O.",
+            player_cell_type: CellType::PLAYER_2,
+            expected_position_to_play: Coord { x: 0, y: 0 }
+        },
+
+        given_empty_center_then_return_center: RuleTest {
+            board: "X../.../...",
+            player_cell_type: CellType::PLAYER_2,
+            expected_position_to_play: Coord { x: 1, y: 1 }
+        },
+
+        given_opponent_corner_then_return_opposite_corner: RuleTest {
+            board: "..X/.O./...",
+            player_cell_type: CellType::PLAYER_2,
+            expected_position_to_play: Coord { x: 0, y: 2 }
+        },
+
+        given_empty_board_then_return_center: RuleTest {
+            board: ".../.../...",
+            player_cell_type: CellType::PLAYER_1,
+            expected_position_to_play: Coord { x: 1, y: 1 }
+        },
+
+        given_opponent_center_then_return_empty_corner: RuleTest {
+            board: ".../.O./...",
+            player_cell_type: CellType::PLAYER_1,
+            expected_position_to_play: Coord { x: 0, y: 0 }
+        },
+
+        given_only_sides_left_then_return_empty_side: RuleTest {
+            board: "X.O/.O./X.X",
+            player_cell_type: CellType::PLAYER_2,
+            expected_position_to_play: Coord { x: 1, y: 2 }
+        },
+    }
+
+    fn assert_never_loses(
+        game_board: Box<dyn board::Game>,
+        to_move: CellType,
+        analyzer_cell_type: CellType,
+    ) {
+        match game_board.is_game_over() {
+            GameOverType::PLAYING => (),
+            GameOverType::DRAW => return,
+            result => {
+                let winner = match result {
+                    GameOverType::PLAYER_1_WIN => CellType::PLAYER_1,
+                    _ => CellType::PLAYER_2,
+                };
+                assert_eq!(winner, analyzer_cell_type);
+                return;
+            }
+        }
+
+        if to_move == analyzer_cell_type {
+            let best_move = heuristic::new()
+                .get_best_move(Box::new(game_board.get_board_state()), to_move)
+                .unwrap();
+            assert_never_loses(
+                game_board.try_set_player(best_move, to_move).unwrap(),
+                to_move.opponent(),
+                analyzer_cell_type,
+            );
+            return;
+        }
+
+        for position in 0..9 {
+            let coord = Coord {
+                x: position % 3,
+                y: position / 3,
+            };
+            if let Ok(next_game_board) = game_board.try_set_player(coord, to_move) {
+                assert_never_loses(next_game_board, to_move.opponent(), analyzer_cell_type);
+            }
+        }
+    }
+
+    #[test]
+    fn given_player_1_heuristic_then_never_lose_against_any_replies() {
+        assert_never_loses(board::new(), CellType::PLAYER_1, CellType::PLAYER_1);
+    }
+
+    #[test]
+    fn given_player_2_heuristic_then_never_lose_against_any_replies() {
+        assert_never_loses(board::new(), CellType::PLAYER_1, CellType::PLAYER_2);
+    }
+}
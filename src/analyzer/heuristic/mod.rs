@@ -0,0 +1,148 @@
+mod heuristic_tests;
+
+use crate::analyzer::{get_fork_positions, get_winning_positions, is_playable, Analyzer};
+use crate::board::{self, Board, CellType, Coord};
+
+/// Analyzer following Newell and Simon's rules, without any search. The
+/// first rule that applies picks the move:
+///
+/// 1. Win: complete a line.
+/// 2. Block: stop the opponent from completing a line.
+/// 3. Fork: create two winning positions at once.
+/// 4. Block fork: take the opponent's only fork position, or force them to
+///    answer a two in a row in a way that does not give them a fork.
+/// 5. Center.
+/// 6. Opposite corner: the corner facing one the opponent holds.
+/// 7. Empty corner.
+/// 8. Empty side.
+pub struct HeuristicAnalyzer {}
+
+impl Analyzer for HeuristicAnalyzer {
+    fn get_best_move(
+        &self,
+        game_board: Box<dyn board::Game>,
+        for_cell_type: CellType,
+    ) -> Option<Coord> {
+        let board_state = game_board.get_board_state();
+        if !is_playable(&board_state, for_cell_type) {
+            return None;
+        }
+
+        return get_rule_position(&board_state, for_cell_type)
+            .map(|position| board_state.coord_of(position));
+    }
+}
+
+pub fn new() -> HeuristicAnalyzer {
+    return HeuristicAnalyzer {};
+}
+
+fn get_rule_position(board: &Board, for_cell_type: CellType) -> Option<usize> {
+    let opponent = for_cell_type.opponent();
+
+    if let Some(&position) = get_winning_positions(board, for_cell_type).first() {
+        return Some(position);
+    }
+    if let Some(&position) = get_winning_positions(board, opponent).first() {
+        return Some(position);
+    }
+    if let Some(&position) = get_fork_positions(board, for_cell_type).first() {
+        return Some(position);
+    }
+    if let Some(position) = get_fork_blocking_position(board, for_cell_type) {
+        return Some(position);
+    }
+    if let Some(position) = get_center_position(board) {
+        return Some(position);
+    }
+    if let Some(position) = get_opposite_corner_position(board, opponent) {
+        return Some(position);
+    }
+
+    let corners = get_corner_positions(board);
+    if let Some(&position) = corners
+        .iter()
+        .find(|&&corner| board[corner] == CellType::NON)
+    {
+        return Some(position);
+    }
+
+    return get_side_position(board);
+}
+
+fn get_fork_blocking_position(board: &Board, for_cell_type: CellType) -> Option<usize> {
+    let opponent_forks = get_fork_positions(board, for_cell_type.opponent());
+    match opponent_forks.len() {
+        0 => return None,
+        1 => return Some(opponent_forks[0]),
+        _ => (),
+    }
+
+    // Several forks cannot all be taken at once. Prefer a fork position that
+    // also forces a reply, then any forcing move, then give up one fork.
+    let empty_positions = (0..board.len()).filter(|&position| board[position] == CellType::NON);
+    return opponent_forks
+        .iter()
+        .copied()
+        .chain(empty_positions)
+        .find(|&position| forces_safe_reply(board, position, for_cell_type))
+        .or(Some(opponent_forks[0]));
+}
+
+/// Whether playing `position` makes a two in a row whose forced answer does
+/// not leave the opponent with two winning positions.
+fn forces_safe_reply(board: &Board, position: usize, for_cell_type: CellType) -> bool {
+    let mut board = board.clone();
+    board[position] = for_cell_type;
+
+    let threats = get_winning_positions(&board, for_cell_type);
+    if threats.len() != 1 {
+        return false;
+    }
+
+    let opponent = for_cell_type.opponent();
+    board[threats[0]] = opponent;
+    return get_winning_positions(&board, opponent).len() < 2;
+}
+
+fn get_center_position(board: &Board) -> Option<usize> {
+    if board.width.is_multiple_of(2) || board.height.is_multiple_of(2) {
+        return None;
+    }
+
+    let center = (board.height / 2) * board.width + board.width / 2;
+    if board[center] != CellType::NON {
+        return None;
+    }
+    return Some(center);
+}
+
+/// Top left, top right, bottom left and bottom right.
+fn get_corner_positions(board: &Board) -> [usize; 4] {
+    let last = board.len() - 1;
+    return [0, board.width - 1, last + 1 - board.width, last];
+}
+
+fn get_opposite_corner_position(board: &Board, opponent: CellType) -> Option<usize> {
+    let corners = get_corner_positions(board);
+    for (index, &corner) in corners.iter().enumerate() {
+        let opposite_corner = corners[3 - index];
+        if board[corner] == opponent && board[opposite_corner] == CellType::NON {
+            return Some(opposite_corner);
+        }
+    }
+    return None;
+}
+
+fn get_side_position(board: &Board) -> Option<usize> {
+    let is_on_edge = |position: usize| {
+        let Coord { x, y } = board.coord_of(position);
+        x == 0 || y == 0 || x == board.width as isize - 1 || y == board.height as isize - 1
+    };
+
+    let empty_positions = (0..board.len()).filter(|&position| board[position] == CellType::NON);
+    return empty_positions
+        .clone()
+        .find(|&position| is_on_edge(position))
+        .or_else(|| empty_positions.clone().next());
+}
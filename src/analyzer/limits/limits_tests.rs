@@ -0,0 +1,245 @@
+#[cfg(test)]
+mod limits_tests {
+    use crate::analyzer::limits::{Clock, SearchLimits};
+    use crate::analyzer::{self, Analyzer, Difficulty};
+    use crate::board::{self, Board, CellType, Coord};
+    use std::cell::Cell;
+    use std::time::{Duration, Instant};
+
+    /// Clock that moves forward by `step` every time it is read.
+    struct MockClock {
+        now: Cell<Instant>,
+        step: Duration,
+    }
+
+    impl MockClock {
+        fn new(start: Instant, step: Duration) -> MockClock {
+            return MockClock {
+                now: Cell::new(start),
+                step,
+            };
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> Instant {
+            let now = self.now.get();
+            self.now.set(now + self.step);
+            return now;
+        }
+    }
+
+    fn parse(board: &str) -> Box<Board> {
+        return Box::new(board.parse::<Board>().unwrap());
+    }
+
+    #[test]
+    fn given_no_limits_then_complete_with_the_unlimited_move() {
+        let positions = [
+            (".../.../...", CellType::PLAYER_1),
+            ("X../.O./...", CellType::PLAYER_1),
+            ("XO./.X./..O", CellType::PLAYER_1),
+            ("X.X/.O./O..", CellType::PLAYER_2),
+        ];
+
+        for (board, to_move) in positions {
+            let game_analyzer = analyzer::new(Difficulty::PERFECT);
+
+            let result = game_analyzer.get_best_move_with_limits(
+                parse(board),
+                to_move,
+                SearchLimits::default(),
+            );
+
+            assert!(result.completed);
+            assert_eq!(
+                result.best_move,
+                analyzer::new(Difficulty::PERFECT).get_best_move(parse(board), to_move)
+            );
+        }
+    }
+
+    #[test]
+    fn given_max_depth_of_one_then_find_immediate_win() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+        let limits = SearchLimits {
+            max_depth: Some(1),
+            ..SearchLimits::default()
+        };
+
+        let result = game_analyzer.get_best_move_with_limits(
+            parse("OO./XX./X.."),
+            CellType::PLAYER_2,
+            limits,
+        );
+
+        assert_eq!(result.best_move, Some(Coord { x: 2, y: 0 }));
+        assert_eq!(result.depth, 1);
+        assert!(!result.completed);
+    }
+
+    #[test]
+    fn given_max_depth_then_stop_at_that_depth() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+        let limits = SearchLimits {
+            max_depth: Some(3),
+            ..SearchLimits::default()
+        };
+
+        let result =
+            game_analyzer.get_best_move_with_limits(board::new(), CellType::PLAYER_1, limits);
+
+        assert_eq!(result.depth, 3);
+        assert!(!result.completed);
+        assert!(result.best_move.is_some());
+    }
+
+    #[test]
+    fn given_max_nodes_then_stop_early_with_a_legal_move() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+        let limits = SearchLimits {
+            max_nodes: Some(500),
+            ..SearchLimits::default()
+        };
+
+        let result =
+            game_analyzer.get_best_move_with_limits(board::new(), CellType::PLAYER_1, limits);
+
+        assert!(!result.completed);
+        assert!(result.nodes <= 501);
+        assert!(result.depth < 9);
+        let best_move = result.best_move.unwrap();
+        assert!((0..3).contains(&best_move.x) && (0..3).contains(&best_move.y));
+    }
+
+    #[test]
+    fn given_deadline_then_result_depends_only_on_the_clock() {
+        let start = Instant::now();
+        let limits = SearchLimits {
+            deadline: Some(start + Duration::from_millis(300)),
+            ..SearchLimits::default()
+        };
+
+        let results: Vec<_> = (0..2)
+            .map(|_| {
+                let clock = MockClock::new(start, Duration::from_millis(1));
+                return analyzer::new(Difficulty::PERFECT).get_best_move_with_clock(
+                    board::new(),
+                    CellType::PLAYER_1,
+                    limits,
+                    &clock,
+                );
+            })
+            .collect();
+
+        assert_eq!(results[0], results[1]);
+        assert!(!results[0].completed);
+        assert_eq!(results[0].nodes, 301);
+        assert!(results[0].depth > 0);
+    }
+
+    #[test]
+    fn given_max_time_then_count_it_from_the_start_of_the_search() {
+        let start = Instant::now();
+        let clock = MockClock::new(start, Duration::from_millis(1));
+        let limits = SearchLimits {
+            max_time: Some(Duration::from_millis(300)),
+            ..SearchLimits::default()
+        };
+
+        let result = analyzer::new(Difficulty::PERFECT).get_best_move_with_clock(
+            board::new(),
+            CellType::PLAYER_1,
+            limits,
+            &clock,
+        );
+
+        assert!(!result.completed);
+        assert_eq!(result.nodes, 300);
+    }
+
+    #[test]
+    fn given_passed_deadline_then_return_first_ordered_move() {
+        let start = Instant::now();
+        let clock = MockClock::new(start, Duration::ZERO);
+        let limits = SearchLimits {
+            deadline: Some(start),
+            ..SearchLimits::default()
+        };
+
+        let result = analyzer::new(Difficulty::PERFECT).get_best_move_with_clock(
+            parse("XX./OO./..."),
+            CellType::PLAYER_1,
+            limits,
+            &clock,
+        );
+
+        assert_eq!(result.best_move, Some(Coord { x: 2, y: 0 }));
+        assert_eq!(result.depth, 0);
+        assert!(!result.completed);
+    }
+
+    #[test]
+    fn given_larger_board_and_depth_limit_then_return_quickly() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+        let limits = SearchLimits {
+            max_depth: Some(2),
+            ..SearchLimits::default()
+        };
+
+        let result = game_analyzer.get_best_move_with_limits(
+            board::new_with_size(5, 5, 4),
+            CellType::PLAYER_1,
+            limits,
+        );
+
+        assert_eq!(result.depth, 2);
+        assert!(!result.completed);
+        assert!(result.best_move.is_some());
+    }
+
+    #[test]
+    fn given_illegal_position_then_return_no_move() {
+        let result = analyzer::new(Difficulty::PERFECT).get_best_move_with_limits(
+            parse("XX./X../..."),
+            CellType::PLAYER_2,
+            SearchLimits::default(),
+        );
+
+        assert_eq!(result.best_move, None);
+    }
+
+    #[test]
+    fn given_move_picked_without_search_then_not_completed() {
+        let result = analyzer::new(Difficulty::RANDOM).get_best_move_with_limits(
+            board::new(),
+            CellType::PLAYER_1,
+            SearchLimits::default(),
+        );
+
+        assert!(result.best_move.is_some());
+        assert!(!result.completed);
+        assert_eq!(result.depth, 0);
+    }
+
+    #[test]
+    fn given_limits_set_on_analyzer_then_get_best_move_uses_them() {
+        let limits = SearchLimits {
+            max_nodes: Some(2000),
+            ..SearchLimits::default()
+        };
+        let mut game_analyzer = analyzer::new(Difficulty::PERFECT);
+        game_analyzer.set_limits(limits);
+
+        let best_move =
+            game_analyzer.get_best_move(board::new_with_size(7, 7, 5), CellType::PLAYER_1);
+
+        let limited = analyzer::new(Difficulty::PERFECT).get_best_move_with_limits(
+            board::new_with_size(7, 7, 5),
+            CellType::PLAYER_1,
+            limits,
+        );
+        assert!(!limited.completed);
+        assert_eq!(best_move, limited.best_move);
+    }
+}
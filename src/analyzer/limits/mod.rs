@@ -0,0 +1,51 @@
+//! Bounds on how long a search may run, for callers that need a move within
+//! a fixed budget.
+
+mod limits_tests;
+
+use crate::board::Coord;
+use std::time::{Duration, Instant};
+
+/// Limits on one search. `None` leaves that limit out.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SearchLimits {
+    /// Deepest iteration to search, in plies from the position.
+    pub max_depth: Option<u32>,
+    /// Positions the search may visit.
+    pub max_nodes: Option<u64>,
+    /// Moment at which the search stops, read from the clock it is given.
+    pub deadline: Option<Instant>,
+    /// Time the search may take from when it starts. Unlike `deadline`, it
+    /// applies afresh to every move of an analyzer given these limits.
+    pub max_time: Option<Duration>,
+}
+
+/// The outcome of a limited search.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SearchResult {
+    /// Best move of the deepest iteration that finished. Before any did, the
+    /// first move of the search order, which wins or blocks when it can.
+    pub best_move: Option<Coord>,
+    /// Whether a search ran and every line was searched to the end of the
+    /// game, in which case `best_move` is the move of the unlimited search.
+    /// False for moves picked without searching.
+    pub completed: bool,
+    /// Plies searched by the deepest iteration that finished.
+    pub depth: u32,
+    /// Positions visited over all iterations.
+    pub nodes: u64,
+}
+
+/// Source of the current time for deadlines, so that tests can control it.
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+/// The clock of the operating system.
+pub struct SystemClock {}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        return Instant::now();
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod mcts_tests {
+    use crate::analyzer::mcts::{self, Budget, MctsConfig, RolloutPolicy};
+    use crate::analyzer::{self, Analyzer, Difficulty};
+    use crate::arena::{self, Openings};
+    use crate::board::{self, Board, CellType, Coord};
+    use std::time::Duration;
+
+    fn config_with_iterations(iterations: usize) -> MctsConfig {
+        return MctsConfig {
+            budget: Budget::ITERATIONS(iterations),
+            ..MctsConfig::default()
+        };
+    }
+
+    #[test]
+    fn given_winning_line_then_return_winning_coordinate() {
+        let game_analyzer = mcts::new_with_seed(config_with_iterations(200), 1);
+        let game_board: Board = "OO./XX./X..".parse().unwrap();
+
+        let best_move = game_analyzer.get_best_move(Box::new(game_board), CellType::PLAYER_2);
+
+        assert_eq!(best_move, Some(Coord { x: 2, y: 0 }));
+    }
+
+    #[test]
+    fn given_opponent_line_then_return_blocking_coordinate() {
+        let game_analyzer = mcts::new_with_seed(config_with_iterations(500), 1);
+        let game_board: Board = "X../XO./...".parse().unwrap();
+
+        let best_move = game_analyzer.get_best_move(Box::new(game_board), CellType::PLAYER_2);
+
+        assert_eq!(best_move, Some(Coord { x: 0, y: 2 }));
+    }
+
+    #[test]
+    fn given_finished_game_then_return_no_move() {
+        let game_analyzer = mcts::new_with_seed(config_with_iterations(100), 1);
+        let game_board: Board = "XXX/OO./...".parse().unwrap();
+
+        assert_eq!(
+            game_analyzer.get_best_move(Box::new(game_board), CellType::PLAYER_2),
+            None
+        );
+    }
+
+    #[test]
+    fn given_same_seed_then_return_same_moves() {
+        let config = MctsConfig {
+            budget: Budget::ITERATIONS(300),
+            exploration: 0.7,
+            rollout_policy: RolloutPolicy::RANDOM,
+        };
+        let game_analyzer = mcts::new_with_seed(config, 9);
+        let replay_analyzer = mcts::new_with_seed(config, 9);
+
+        for _ in 0..5 {
+            assert_eq!(
+                game_analyzer.get_best_move(board::new(), CellType::PLAYER_1),
+                replay_analyzer.get_best_move(board::new(), CellType::PLAYER_1)
+            );
+        }
+    }
+
+    #[test]
+    fn given_time_budget_then_return_a_move() {
+        let config = MctsConfig {
+            budget: Budget::TIME(Duration::from_millis(20)),
+            ..MctsConfig::default()
+        };
+
+        let best_move = mcts::new(config).get_best_move(board::new(), CellType::PLAYER_1);
+
+        assert!(best_move.is_some());
+    }
+
+    #[test]
+    fn given_perfect_opponent_then_draw_every_game() {
+        let game_analyzer = mcts::new_with_seed(config_with_iterations(3_000), 3);
+
+        let result = arena::play_match(
+            &game_analyzer,
+            &analyzer::new(Difficulty::PERFECT),
+            6,
+            &Openings::EMPTY,
+        );
+
+        assert_eq!(result.total().draws, 6);
+    }
+
+    #[test]
+    fn given_larger_board_then_beat_random_player() {
+        let game_analyzer = mcts::new_with_seed(config_with_iterations(500), 4);
+        let opening = board::GameState::from_board(Board::new(5, 5, 4), CellType::PLAYER_1);
+
+        let result = arena::play_match(
+            &game_analyzer,
+            &analyzer::new_with_seed(Difficulty::RANDOM, 4),
+            2,
+            &Openings::LISTED(vec![opening]),
+        );
+
+        assert_eq!(result.total().wins, 2);
+    }
+}
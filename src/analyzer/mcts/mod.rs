@@ -0,0 +1,316 @@
+//! Monte Carlo tree search (UCT). Instead of searching every line, it plays
+//! many quick games from the current position and spends more of them on the
+//! moves that have done well so far, which keeps it usable on boards far too
+//! large for the full search.
+
+mod mcts_tests;
+
+use crate::analyzer::{get_line_move, is_playable, Analyzer};
+use crate::board::{self, Board, CellType, Coord, Game, GameOverType};
+use crate::random::{self, Random};
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// How long the search runs for each move.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Budget {
+    /// Number of games played out from the position.
+    ITERATIONS(usize),
+    /// Wall-clock time spent searching.
+    TIME(Duration),
+}
+
+/// How the games are played out once they leave the tree.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RolloutPolicy {
+    /// Uniformly random moves.
+    RANDOM,
+    /// Wins and blocks found by the line heuristic, random moves otherwise.
+    LINE_HEURISTIC,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MctsConfig {
+    pub budget: Budget,
+    /// Weight of the exploration term of UCT. Higher values try rarely
+    /// played moves more often.
+    pub exploration: f64,
+    pub rollout_policy: RolloutPolicy,
+}
+
+impl Default for MctsConfig {
+    fn default() -> MctsConfig {
+        return MctsConfig {
+            budget: Budget::ITERATIONS(10_000),
+            exploration: std::f64::consts::SQRT_2,
+            rollout_policy: RolloutPolicy::LINE_HEURISTIC,
+        };
+    }
+}
+
+pub struct MctsAnalyzer {
+    config: MctsConfig,
+    random: RefCell<Random>,
+}
+
+/// Rewards are from the point of view of `mover`, the player whose move led
+/// to the node: 1 for a win, 0.5 for a draw and 0 for a loss.
+struct Node {
+    position: usize,
+    mover: CellType,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    untried_positions: Vec<usize>,
+    visits: u32,
+    reward: f64,
+    /// The exact reward once the result of the node is known for certain,
+    /// either because the game is over or because all of its replies are.
+    proven_reward: Option<f64>,
+}
+
+impl MctsAnalyzer {
+    pub fn config(&self) -> MctsConfig {
+        return self.config;
+    }
+
+    fn search(&self, board_state: &Board, for_cell_type: CellType) -> Option<usize> {
+        let mut random = self.random.borrow_mut();
+        let mut nodes = vec![Node {
+            position: usize::MAX,
+            mover: for_cell_type.opponent(),
+            parent: None,
+            children: vec![],
+            untried_positions: get_empty_positions(board_state),
+            visits: 0,
+            reward: 0.0,
+            proven_reward: None,
+        }];
+
+        let started = Instant::now();
+        let mut iterations = 0;
+        while nodes[0].proven_reward.is_none() {
+            let finished = match self.config.budget {
+                Budget::ITERATIONS(limit) => iterations >= limit,
+                Budget::TIME(duration) => started.elapsed() >= duration,
+            };
+            if finished {
+                break;
+            }
+            iterations += 1;
+
+            let mut board_state = board_state.clone();
+            let leaf = self.select_and_expand(&mut nodes, &mut board_state, &mut random);
+            let reward = match nodes[leaf].proven_reward {
+                Some(reward) => reward,
+                None => {
+                    let to_move = nodes[leaf].mover.opponent();
+                    let result = self.roll_out(&mut board_state, to_move, &mut random);
+                    get_reward(result, nodes[leaf].mover)
+                }
+            };
+            back_propagate(&mut nodes, leaf, reward);
+        }
+
+        return choose_root_move(&nodes);
+    }
+
+    /// Walks down the tree with UCT, playing the moves on `board_state`, and
+    /// adds one new node at the end unless the result there is already
+    /// known. Returns the last node reached.
+    fn select_and_expand(
+        &self,
+        nodes: &mut Vec<Node>,
+        board_state: &mut Board,
+        random: &mut Random,
+    ) -> usize {
+        let mut node = 0;
+        while nodes[node].untried_positions.is_empty() && nodes[node].proven_reward.is_none() {
+            node = self.select_child(nodes, node);
+            board_state[nodes[node].position] = nodes[node].mover;
+        }
+
+        if nodes[node].proven_reward.is_some() || nodes[node].untried_positions.is_empty() {
+            return node;
+        }
+
+        let untried_positions = &mut nodes[node].untried_positions;
+        let position = untried_positions.swap_remove(random.next_below(untried_positions.len()));
+        let mover = nodes[node].mover.opponent();
+        board_state[position] = mover;
+
+        let (untried_positions, proven_reward) = match board_state.is_game_over() {
+            GameOverType::PLAYING => (get_empty_positions(board_state), None),
+            GameOverType::DRAW => (vec![], Some(0.5)),
+            _ => (vec![], Some(1.0)),
+        };
+        nodes.push(Node {
+            position,
+            mover,
+            parent: Some(node),
+            children: vec![],
+            untried_positions,
+            visits: 0,
+            reward: 0.0,
+            proven_reward,
+        });
+        let child = nodes.len() - 1;
+        nodes[node].children.push(child);
+        return child;
+    }
+
+    /// The child with the best UCT value. Children with a known result count
+    /// with that result instead of their average.
+    fn select_child(&self, nodes: &[Node], node: usize) -> usize {
+        let log_visits = (nodes[node].visits.max(1) as f64).ln();
+        let mut best: Option<(usize, f64)> = None;
+
+        for &child in &nodes[node].children {
+            let visits = nodes[child].visits.max(1) as f64;
+            let average = nodes[child]
+                .proven_reward
+                .unwrap_or(nodes[child].reward / visits);
+            let value = average + self.config.exploration * (log_visits / visits).sqrt();
+            if best.is_none_or(|(_, best_value)| value > best_value) {
+                best = Some((child, value));
+            }
+        }
+
+        return best.expect("a node without untried moves has children").0;
+    }
+
+    /// Plays `board_state` out to the end with `to_move` moving first.
+    fn roll_out(
+        &self,
+        board_state: &mut Board,
+        mut to_move: CellType,
+        random: &mut Random,
+    ) -> GameOverType {
+        loop {
+            let position = match self.config.rollout_policy {
+                RolloutPolicy::LINE_HEURISTIC => get_line_move(board_state, to_move),
+                RolloutPolicy::RANDOM => None,
+            };
+            let position = position.unwrap_or_else(|| {
+                let empty_positions = get_empty_positions(board_state);
+                empty_positions[random.next_below(empty_positions.len())]
+            });
+            board_state[position] = to_move;
+
+            let result = board_state.is_game_over();
+            if result != GameOverType::PLAYING {
+                return result;
+            }
+            to_move = to_move.opponent();
+        }
+    }
+}
+
+impl Analyzer for MctsAnalyzer {
+    fn get_best_move(
+        &self,
+        game_board: Box<dyn board::Game>,
+        for_cell_type: CellType,
+    ) -> Option<Coord> {
+        let board_state = game_board.get_board_state();
+        if !is_playable(&board_state, for_cell_type) {
+            return None;
+        }
+
+        return self
+            .search(&board_state, for_cell_type)
+            .map(|position| board_state.coord_of(position));
+    }
+}
+
+pub fn new(config: MctsConfig) -> MctsAnalyzer {
+    return new_with_seed(config, random::seed_from_time());
+}
+
+/// Same as `new`, with the random choices of the search drawn from `seed`.
+/// A budget of iterations then always gives the same moves.
+pub fn new_with_seed(config: MctsConfig, seed: u64) -> MctsAnalyzer {
+    return MctsAnalyzer {
+        config,
+        random: RefCell::new(Random::new(seed)),
+    };
+}
+
+fn get_empty_positions(board: &Board) -> Vec<usize> {
+    return (0..board.len())
+        .filter(|&position| board[position] == CellType::NON)
+        .collect();
+}
+
+/// The reward of `result` for `mover`.
+fn get_reward(result: GameOverType, mover: CellType) -> f64 {
+    match (result, mover) {
+        (GameOverType::PLAYER_1_WIN, CellType::PLAYER_1)
+        | (GameOverType::PLAYER_2_WIN, CellType::PLAYER_2) => return 1.0,
+        (GameOverType::PLAYER_1_WIN, _) | (GameOverType::PLAYER_2_WIN, _) => return 0.0,
+        _ => return 0.5,
+    }
+}
+
+/// Adds `reward`, won by the mover of `leaf`, to `leaf` and every node above
+/// it, and marks nodes whose result has become certain.
+fn back_propagate(nodes: &mut [Node], leaf: usize, reward: f64) {
+    let mut node = Some(leaf);
+    while let Some(current) = node {
+        nodes[current].visits += 1;
+        nodes[current].reward += match nodes[current].mover == nodes[leaf].mover {
+            true => reward,
+            false => 1.0 - reward,
+        };
+
+        if nodes[current].proven_reward.is_none() {
+            nodes[current].proven_reward = get_proven_reward(nodes, current);
+        }
+        node = nodes[current].parent;
+    }
+}
+
+/// The result of `node` if one of its replies wins for the opponent, or if
+/// every reply has a known result.
+fn get_proven_reward(nodes: &[Node], node: usize) -> Option<f64> {
+    let child_rewards: Vec<Option<f64>> = nodes[node]
+        .children
+        .iter()
+        .map(|&child| nodes[child].proven_reward)
+        .collect();
+
+    if child_rewards.contains(&Some(1.0)) {
+        return Some(0.0);
+    }
+    if !nodes[node].untried_positions.is_empty() || child_rewards.contains(&None) {
+        return None;
+    }
+
+    let best_reply = child_rewards.into_iter().flatten().fold(0.0, f64::max);
+    return Some(1.0 - best_reply);
+}
+
+/// A move known to win, otherwise the most visited move not known to lose.
+fn choose_root_move(nodes: &[Node]) -> Option<usize> {
+    let children = &nodes[0].children;
+    if let Some(&child) = children
+        .iter()
+        .find(|&&child| nodes[child].proven_reward == Some(1.0))
+    {
+        return Some(nodes[child].position);
+    }
+
+    let mut best: Option<(usize, (bool, u32))> = None;
+    for &child in children {
+        let rank = (nodes[child].proven_reward != Some(0.0), nodes[child].visits);
+        if best.is_none_or(|(_, best_rank)| rank > best_rank) {
+            best = Some((child, rank));
+        }
+    }
+
+    return match best {
+        Some((child, _)) => Some(nodes[child].position),
+        None => nodes[0].untried_positions.first().copied(),
+    };
+}
@@ -1,8 +1,372 @@
 mod analyzer_tests;
+pub mod book;
+pub mod explanation;
+pub mod heuristic;
+pub mod limits;
+pub mod mcts;
+pub mod score;
+pub mod transposition;
 
-use crate::board::{self, CellType, Coord};
+use crate::board::{self, Board, CellType, Coord, Game, GameOverType, Line};
+use crate::random::{self, Random};
+use explanation::MoveExplanation;
+use limits::{Clock, SearchLimits, SearchResult, SystemClock};
+use score::MoveScore;
+use std::cell::RefCell;
+#[cfg(feature = "parallel")]
+use std::thread;
+use std::time::Instant;
+use transposition::{Bound, Entry, SharedTranspositionTable, Statistics};
 
-pub struct AnalyzerState {}
+/// Score of a won position. Wins found sooner score higher, so a win `n`
+/// plies away from the root is worth `SCORE_WIN - n`.
+const SCORE_WIN: i32 = 1000;
+
+/// How strong the analyzer plays. Each move is picked by the full search,
+/// by the win/block line heuristic or at random, in these proportions:
+///
+/// | Difficulty | Search | Heuristic | Random |
+/// |------------|--------|-----------|--------|
+/// | `RANDOM`   | 0%     | 0%        | 100%   |
+/// | `EASY`     | 20%    | 40%       | 40%    |
+/// | `MEDIUM`   | 60%    | 30%       | 10%    |
+/// | `PERFECT`  | 100%   | 0%        | 0%     |
+///
+/// When the heuristic finds no line to win or block, a random move is played.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Difficulty {
+    RANDOM,
+    EASY,
+    MEDIUM,
+    PERFECT,
+}
+
+impl Difficulty {
+    /// Percentages of moves picked by the search and by the heuristic. The
+    /// remaining moves are random.
+    fn move_mix(&self) -> (usize, usize) {
+        match self {
+            Difficulty::RANDOM => (0, 0),
+            Difficulty::EASY => (20, 40),
+            Difficulty::MEDIUM => (60, 30),
+            Difficulty::PERFECT => (100, 0),
+        }
+    }
+}
+
+/// Where a move comes from, rolled for each move by `Difficulty::move_mix`.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MoveSource {
+    SEARCH,
+    HEURISTIC,
+    RANDOM,
+}
+
+pub struct AnalyzerState {
+    difficulty: Difficulty,
+    /// Applied to every search `get_best_move` runs.
+    limits: SearchLimits,
+    random: RefCell<Random>,
+    cache: SharedTranspositionTable,
+    /// Threads that score the root moves of a full search.
+    #[cfg(feature = "parallel")]
+    threads: usize,
+}
+
+impl AnalyzerState {
+    /// Hit and miss counts of the transposition cache shared by every search
+    /// this analyzer has run.
+    pub fn cache_statistics(&self) -> Statistics {
+        return self.cache.statistics();
+    }
+
+    pub fn difficulty(&self) -> Difficulty {
+        return self.difficulty;
+    }
+
+    pub fn limits(&self) -> SearchLimits {
+        return self.limits;
+    }
+
+    /// Limits every later search of `get_best_move`, which then deepens one
+    /// ply at a time and plays the best move found when a limit is reached.
+    /// Without limits, the default, it searches to the end of the game.
+    pub fn set_limits(&mut self, limits: SearchLimits) {
+        self.limits = limits;
+    }
+
+    /// Scores the root moves of every later full search on `threads`
+    /// threads, one per core by default. Moves and scores do not depend on
+    /// it.
+    #[cfg(feature = "parallel")]
+    pub fn set_threads(&mut self, threads: usize) {
+        self.threads = threads.max(1);
+    }
+
+    /// Like `get_best_move`, but the search stops once `limits` are reached,
+    /// deepening one ply at a time so that a move is ready whenever it does.
+    /// `limits` replace those set with `set_limits` for this move.
+    pub fn get_best_move_with_limits(
+        &self,
+        game_board: Box<dyn board::Game>,
+        for_cell_type: CellType,
+        limits: SearchLimits,
+    ) -> SearchResult {
+        return self.get_best_move_with_clock(game_board, for_cell_type, limits, &SystemClock {});
+    }
+
+    /// Like `get_best_move_with_limits`, with the deadline read from `clock`.
+    /// Moves that `difficulty` picks without searching are not completed, as
+    /// no search ran.
+    pub fn get_best_move_with_clock(
+        &self,
+        game_board: Box<dyn board::Game>,
+        for_cell_type: CellType,
+        limits: SearchLimits,
+        clock: &dyn Clock,
+    ) -> SearchResult {
+        let mut board_state = game_board.get_board_state();
+        let mut result = SearchResult {
+            best_move: None,
+            completed: false,
+            depth: 0,
+            nodes: 0,
+        };
+        if !is_playable(&board_state, for_cell_type) {
+            return result;
+        }
+
+        match self.roll_move_source() {
+            MoveSource::SEARCH => {
+                return self.deepen_search(&mut board_state, for_cell_type, limits, clock);
+            }
+            source => {
+                result.best_move = self
+                    .choose_move_from(source, &mut board_state, for_cell_type)
+                    .map(|position| board_state.coord_of(position));
+                return result;
+            }
+        }
+    }
+
+    fn roll_move_source(&self) -> MoveSource {
+        let (search_percentage, heuristic_percentage) = self.difficulty.move_mix();
+        let roll = self.random.borrow_mut().next_below(100);
+
+        if roll < search_percentage {
+            return MoveSource::SEARCH;
+        } else if roll < search_percentage + heuristic_percentage {
+            return MoveSource::HEURISTIC;
+        } else {
+            return MoveSource::RANDOM;
+        }
+    }
+
+    /// Picks a move the way `difficulty` asks for.
+    fn choose_move(&self, board_state: &mut Board, for_cell_type: CellType) -> Option<usize> {
+        let source = self.roll_move_source();
+        return self.choose_move_from(source, board_state, for_cell_type);
+    }
+
+    fn choose_move_from(
+        &self,
+        source: MoveSource,
+        board_state: &mut Board,
+        for_cell_type: CellType,
+    ) -> Option<usize> {
+        match source {
+            MoveSource::SEARCH if self.limits == SearchLimits::default() => {
+                return self
+                    .search_best_move(board_state, for_cell_type)
+                    .map(|(position, _)| position);
+            }
+            MoveSource::SEARCH => {
+                return self
+                    .deepen_search(board_state, for_cell_type, self.limits, &SystemClock {})
+                    .best_move
+                    .and_then(|coord| board_state.position_of(&coord));
+            }
+            MoveSource::HEURISTIC => {
+                return get_line_move(board_state, for_cell_type)
+                    .or_else(|| self.random_move(board_state));
+            }
+            MoveSource::RANDOM => return self.random_move(board_state),
+        }
+    }
+
+    /// Scores every legal move of `game_board` for `for_cell_type`, in board
+    /// order. Uses the same search as `get_best_move`, whose move at
+    /// `Difficulty::PERFECT` always has the best score here.
+    pub fn score_moves(
+        &self,
+        game_board: Box<dyn board::Game>,
+        for_cell_type: CellType,
+    ) -> Vec<(Coord, MoveScore)> {
+        let mut board_state = game_board.get_board_state();
+        if !is_playable(&board_state, for_cell_type) {
+            return vec![];
+        }
+
+        let mut scores = self.search_root_moves(&mut board_state, for_cell_type);
+        scores.sort_by_key(|(position, _)| *position);
+
+        return scores
+            .into_iter()
+            .map(|(position, score)| (board_state.coord_of(position), MoveScore::from_score(score)))
+            .collect();
+    }
+
+    /// Full alpha-beta search from `board_state`. Returns the best move and
+    /// its score.
+    fn search_best_move(
+        &self,
+        board_state: &mut Board,
+        for_cell_type: CellType,
+    ) -> Option<(usize, i32)> {
+        let mut best_move: Option<(usize, i32)> = None;
+
+        // Ties go to the move searched first, which depends only on the
+        // position, so equally good moves are chosen the same way every time.
+        for (position, score) in self.search_root_moves(board_state, for_cell_type) {
+            match best_move {
+                Some((_, best_score)) if best_score >= score => (),
+                _ => best_move = Some((position, score)),
+            }
+        }
+
+        return best_move;
+    }
+
+    /// The exact score of every legal move, in search order.
+    fn search_root_moves(
+        &self,
+        board_state: &mut Board,
+        for_cell_type: CellType,
+    ) -> Vec<(usize, i32)> {
+        let moves = get_ordered_moves(board_state, for_cell_type);
+
+        #[cfg(feature = "parallel")]
+        let scores = score_root_moves_on_threads(
+            &self.cache,
+            board_state,
+            for_cell_type,
+            &moves,
+            self.threads,
+        );
+        #[cfg(not(feature = "parallel"))]
+        let scores = score_root_moves(&self.cache, board_state, for_cell_type, &moves);
+
+        return scores;
+    }
+
+    /// Iterative deepening under `limits`. Each iteration scores the root
+    /// moves in the same order as the full search, so a completed search
+    /// breaks ties the same way.
+    fn deepen_search(
+        &self,
+        board_state: &mut Board,
+        for_cell_type: CellType,
+        limits: SearchLimits,
+        clock: &dyn Clock,
+    ) -> SearchResult {
+        let moves = get_ordered_moves(board_state, for_cell_type);
+        let full_depth = moves.len() as u32;
+        let max_depth = limits.max_depth.unwrap_or(u32::MAX).min(full_depth);
+
+        let mut search = SearchContext::new(&self.cache, limits, clock);
+        let mut best_position = moves.first().copied();
+        let mut finished_depth = 0;
+
+        for depth in 1..=max_depth {
+            let mut best_move: Option<(usize, i32)> = None;
+            for &position in &moves {
+                board_state[position] = for_cell_type;
+                let score = -negamax(
+                    board_state,
+                    for_cell_type.opponent(),
+                    1,
+                    depth - 1,
+                    -SCORE_WIN - 1,
+                    SCORE_WIN + 1,
+                    &mut search,
+                );
+                board_state[position] = CellType::NON;
+
+                if search.aborted {
+                    break;
+                }
+                if best_move.is_none_or(|(_, best_score)| score > best_score) {
+                    best_move = Some((position, score));
+                }
+            }
+
+            if search.aborted {
+                break;
+            }
+            best_position = best_move.map(|(position, _)| position);
+            finished_depth = depth;
+        }
+
+        return SearchResult {
+            best_move: best_position.map(|position| board_state.coord_of(position)),
+            completed: finished_depth == full_depth,
+            depth: finished_depth,
+            nodes: search.nodes,
+        };
+    }
+
+    /// `position` followed by the best replies of both sides until the game
+    /// ends, or until the limits of `search` run out.
+    fn principal_variation(
+        &self,
+        board_state: &Board,
+        position: usize,
+        for_cell_type: CellType,
+        search: &mut SearchContext,
+    ) -> Vec<Coord> {
+        let mut board_state = board_state.clone();
+        let mut variation = vec![board_state.coord_of(position)];
+        let mut to_move = for_cell_type;
+        board_state[position] = to_move;
+
+        while board_state.is_game_over() == GameOverType::PLAYING {
+            to_move = to_move.opponent();
+            let mut best_move: Option<(usize, i32)> = None;
+            for position in get_ordered_moves(&board_state, to_move) {
+                let score = match limited_move_score(search, &mut board_state, position, to_move) {
+                    Some(score) => score,
+                    None => return variation,
+                };
+                if best_move.is_none_or(|(_, best_score)| score > best_score) {
+                    best_move = Some((position, score));
+                }
+            }
+
+            let (position, _) = match best_move {
+                Some(best_move) => best_move,
+                None => break,
+            };
+            variation.push(board_state.coord_of(position));
+            board_state[position] = to_move;
+        }
+
+        return variation;
+    }
+
+    fn random_move(&self, board_state: &Board) -> Option<usize> {
+        let empty_positions: Vec<usize> = (0..board_state.len())
+            .filter(|&position| board_state[position] == CellType::NON)
+            .collect();
+        if empty_positions.is_empty() {
+            return None;
+        }
+
+        let index = self.random.borrow_mut().next_below(empty_positions.len());
+        return Some(empty_positions[index]);
+    }
+}
 
 pub trait Analyzer {
     fn get_best_move(
@@ -10,6 +374,26 @@ pub trait Analyzer {
         game_board: Box<dyn board::Game>,
         for_cell_type: CellType,
     ) -> Option<Coord>;
+
+    /// Explains the move `get_best_move` plays. Analyzers that do not search
+    /// only classify the move, with no continuation or score.
+    fn explain_move(
+        &self,
+        game_board: Box<dyn board::Game>,
+        for_cell_type: CellType,
+    ) -> Option<MoveExplanation> {
+        let board_state = game_board.get_board_state();
+        let coord = self.get_best_move(game_board, for_cell_type)?;
+        let position = board_state.position_of(&coord)?;
+
+        return Some(MoveExplanation {
+            coord,
+            cell_type: for_cell_type,
+            kind: explanation::classify_move(&board_state, position, for_cell_type),
+            principal_variation: vec![coord],
+            score: None,
+        });
+    }
 }
 
 impl Analyzer for AnalyzerState {
@@ -18,47 +402,478 @@ impl Analyzer for AnalyzerState {
         game_board: Box<dyn board::Game>,
         for_cell_type: CellType,
     ) -> Option<Coord> {
-        let board_state = game_board.get_board_state();
-        let pattern_check_list: Vec<[usize; 3]> = vec![
-            [0, 1, 2],
-            [3, 4, 5],
-            [6, 7, 8],
-            [0, 3, 6],
-            [1, 4, 7],
-            [2, 5, 8],
-            [0, 4, 8],
-            [2, 4, 6],
-        ];
-
-        for pattern in pattern_check_list {
-            match get_winning_position(&board_state, for_cell_type, pattern) {
-                Some(position) => return Some(position_to_coord(&position)),
-                _ => (),
-            };
+        let mut board_state = game_board.get_board_state();
+        if !is_playable(&board_state, for_cell_type) {
+            return None;
+        }
 
-            match get_defending_position(&board_state, for_cell_type, pattern) {
-                Some(position) => return Some(position_to_coord(&position)),
-                _ => (),
+        let best_move = self.choose_move(&mut board_state, for_cell_type);
+        return best_move.map(|position| board_state.coord_of(position));
+    }
+
+    fn explain_move(
+        &self,
+        game_board: Box<dyn board::Game>,
+        for_cell_type: CellType,
+    ) -> Option<MoveExplanation> {
+        let mut board_state = game_board.get_board_state();
+        if !is_playable(&board_state, for_cell_type) {
+            return None;
+        }
+
+        let source = self.roll_move_source();
+        let position = self.choose_move_from(source, &mut board_state, for_cell_type)?;
+        let coord = board_state.coord_of(position);
+        let mut explanation = MoveExplanation {
+            coord,
+            cell_type: for_cell_type,
+            kind: explanation::classify_move(&board_state, position, for_cell_type),
+            principal_variation: vec![coord],
+            score: None,
+        };
+
+        // Moves picked without searching are only classified. Searched ones
+        // are scored within the same limits as the move, since a line cut
+        // short by a depth limit has no exact score.
+        let empty_cells = board_state
+            .iter()
+            .filter(|&cell| *cell == CellType::NON)
+            .count() as u32;
+        if source != MoveSource::SEARCH
+            || self
+                .limits
+                .max_depth
+                .is_some_and(|max_depth| max_depth < empty_cells)
+        {
+            return Some(explanation);
+        }
+
+        let mut search = SearchContext::new(&self.cache, self.limits, &SystemClock {});
+        explanation.score =
+            limited_move_score(&mut search, &mut board_state, position, for_cell_type);
+        if explanation.score.is_some() {
+            explanation.principal_variation =
+                self.principal_variation(&board_state, position, for_cell_type, &mut search);
+        }
+        return Some(explanation);
+    }
+}
+
+/// Creates an analyzer playing at `difficulty`, seeded from the clock.
+pub fn new(difficulty: Difficulty) -> AnalyzerState {
+    return new_with_seed(difficulty, random::seed_from_time());
+}
+
+/// Creates an analyzer playing at `difficulty` whose random choices are
+/// fixed by `seed`.
+pub fn new_with_seed(difficulty: Difficulty, seed: u64) -> AnalyzerState {
+    return AnalyzerState {
+        difficulty,
+        limits: SearchLimits::default(),
+        random: RefCell::new(Random::new(seed)),
+        cache: SharedTranspositionTable::new(),
+        #[cfg(feature = "parallel")]
+        threads: thread::available_parallelism().map_or(1, |threads| threads.get()),
+    };
+}
+
+/// Scores `moves` one after the other.
+fn score_root_moves(
+    cache: &SharedTranspositionTable,
+    board_state: &mut Board,
+    for_cell_type: CellType,
+    moves: &[usize],
+) -> Vec<(usize, i32)> {
+    return moves
+        .iter()
+        .map(|&position| {
+            let score = search_move_score(cache, board_state, position, for_cell_type);
+            (position, score)
+        })
+        .collect();
+}
+
+/// Scores `moves` on up to `threads` threads, every thread taking every n-th
+/// move. Each score is exact whatever the other threads have cached, so the
+/// result is the same as `score_root_moves`.
+#[cfg(feature = "parallel")]
+fn score_root_moves_on_threads(
+    cache: &SharedTranspositionTable,
+    board_state: &mut Board,
+    for_cell_type: CellType,
+    moves: &[usize],
+    threads: usize,
+) -> Vec<(usize, i32)> {
+    let threads = threads.min(moves.len());
+    if threads <= 1 {
+        return score_root_moves(cache, board_state, for_cell_type, moves);
+    }
+    let mut scores = vec![0; moves.len()];
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|first| {
+                let mut board = board_state.clone();
+                scope.spawn(move || {
+                    return moves
+                        .iter()
+                        .enumerate()
+                        .skip(first)
+                        .step_by(threads)
+                        .map(|(index, &position)| {
+                            let score =
+                                search_move_score(cache, &mut board, position, for_cell_type);
+                            (index, score)
+                        })
+                        .collect::<Vec<_>>();
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            for (index, score) in handle.join().expect("search threads do not panic") {
+                scores[index] = score;
             }
         }
+    });
 
+    return moves.iter().copied().zip(scores).collect();
+}
+
+/// Score of playing `position`, from the point of view of `for_cell_type`.
+fn search_move_score(
+    cache: &SharedTranspositionTable,
+    board_state: &mut Board,
+    position: usize,
+    for_cell_type: CellType,
+) -> i32 {
+    let mut search = SearchContext::new(cache, SearchLimits::default(), &SystemClock {});
+    return limited_move_score(&mut search, board_state, position, for_cell_type)
+        .expect("a search without limits always finishes");
+}
+
+/// Score of playing `position`, searched to the end of the game, or `None`
+/// once the node or time limits of `search` run out.
+fn limited_move_score(
+    search: &mut SearchContext,
+    board_state: &mut Board,
+    position: usize,
+    for_cell_type: CellType,
+) -> Option<i32> {
+    board_state[position] = for_cell_type;
+    let score = -negamax(
+        board_state,
+        for_cell_type.opponent(),
+        1,
+        u32::MAX,
+        -SCORE_WIN - 1,
+        SCORE_WIN + 1,
+        search,
+    );
+    board_state[position] = CellType::NON;
+
+    if search.aborted {
         return None;
     }
+    return Some(score);
 }
 
-pub fn new() -> impl Analyzer {
-    return AnalyzerState {};
+/// State shared by every node of one search.
+struct SearchContext<'a> {
+    /// Shared by the searches of every thread.
+    cache: &'a SharedTranspositionTable,
+    max_nodes: Option<u64>,
+    /// The earlier of `SearchLimits::deadline` and the end of
+    /// `SearchLimits::max_time`, counted from when the search started.
+    deadline: Option<Instant>,
+    clock: &'a dyn Clock,
+    nodes: u64,
+    /// Set once a limit is reached. Scores returned after that are
+    /// meaningless and must be thrown away.
+    aborted: bool,
 }
 
-fn get_winning_position(
-    board: &Vec<CellType>,
-    for_cell_type: CellType,
-    positions: [usize; 3],
-) -> Option<usize> {
+impl<'a> SearchContext<'a> {
+    fn new(
+        cache: &'a SharedTranspositionTable,
+        limits: SearchLimits,
+        clock: &'a dyn Clock,
+    ) -> SearchContext<'a> {
+        let time_deadline = limits.max_time.map(|max_time| clock.now() + max_time);
+        let deadline = match (limits.deadline, time_deadline) {
+            (Some(deadline), Some(time_deadline)) => Some(deadline.min(time_deadline)),
+            (deadline, time_deadline) => deadline.or(time_deadline),
+        };
+
+        return SearchContext {
+            cache,
+            max_nodes: limits.max_nodes,
+            deadline,
+            clock,
+            nodes: 0,
+            aborted: false,
+        };
+    }
+
+    /// Counts a visited node and checks the node and time limits.
+    fn visit(&mut self) -> bool {
+        self.nodes += 1;
+        let out_of_nodes = self
+            .max_nodes
+            .is_some_and(|max_nodes| self.nodes > max_nodes);
+        let out_of_time = self
+            .deadline
+            .is_some_and(|deadline| self.clock.now() >= deadline);
+
+        self.aborted = self.aborted || out_of_nodes || out_of_time;
+        return !self.aborted;
+    }
+}
+
+/// Alpha-beta negamax. Returns the score of `board` from the point of view of
+/// `to_move`, `ply` moves below the root. Lines are cut `depth` moves from
+/// here and scored as a draw; a depth of at least the number of empty cells
+/// searches to the end of the game.
+fn negamax(
+    board: &mut Board,
+    to_move: CellType,
+    ply: i32,
+    depth: u32,
+    mut alpha: i32,
+    mut beta: i32,
+    search: &mut SearchContext,
+) -> i32 {
+    if !search.visit() {
+        return 0;
+    }
+
+    match board.is_game_over() {
+        GameOverType::PLAYING => (),
+        GameOverType::DRAW => return 0,
+        // The side that just moved has completed a line.
+        _ => return -(SCORE_WIN - ply),
+    }
+
+    // Nothing below this node can beat a win on the next move or lose faster
+    // than on the move after.
+    alpha = alpha.max(-(SCORE_WIN - ply - 1));
+    beta = beta.min(SCORE_WIN - ply - 1);
+    if alpha >= beta {
+        return alpha;
+    }
+
+    let depth = depth.min(board.iter().filter(|&cell| *cell == CellType::NON).count() as u32);
+    if depth == 0 {
+        return 0;
+    }
+    let mut moves = get_ordered_moves(board, to_move);
+
+    let cached = search.cache.get(board, to_move);
+    if let Some(entry) = cached {
+        let value = from_cache_value(entry.value, ply);
+        if entry.depth >= depth {
+            match entry.bound {
+                Bound::EXACT => return value,
+                Bound::LOWER if value >= beta => return value,
+                Bound::UPPER if value <= alpha => return value,
+                _ => (),
+            }
+        }
+
+        if let Some(best_move) = entry.best_move {
+            moves.retain(|&position| position != best_move);
+            moves.insert(0, best_move);
+        }
+    }
+
+    let original_alpha = alpha;
+    let mut best_score = -SCORE_WIN - 1;
+    let mut best_move = None;
+
+    for position in moves {
+        board[position] = to_move;
+        let score = -negamax(
+            board,
+            to_move.opponent(),
+            ply + 1,
+            depth - 1,
+            -beta,
+            -alpha,
+            search,
+        );
+        board[position] = CellType::NON;
+
+        if search.aborted {
+            return 0;
+        }
+
+        if score > best_score {
+            best_score = score;
+            best_move = Some(position);
+        }
+        if score > alpha {
+            alpha = score;
+        }
+        if alpha >= beta {
+            break;
+        }
+    }
+
+    let bound = if best_score <= original_alpha {
+        Bound::UPPER
+    } else if best_score >= beta {
+        Bound::LOWER
+    } else {
+        Bound::EXACT
+    };
+    search.cache.insert(
+        board,
+        to_move,
+        Entry {
+            value: to_cache_value(best_score, ply),
+            bound,
+            best_move,
+            depth,
+        },
+    );
+
+    return best_score;
+}
+
+/// Scores are counted from the root, but a cached position can be reached at
+/// any ply, so wins and losses are stored as counted from the position.
+fn to_cache_value(score: i32, ply: i32) -> i32 {
+    match score {
+        score if score > 0 => return score + ply,
+        score if score < 0 => return score - ply,
+        _ => return 0,
+    }
+}
+
+fn from_cache_value(value: i32, ply: i32) -> i32 {
+    match value {
+        value if value > 0 => return value - ply,
+        value if value < 0 => return value + ply,
+        _ => return 0,
+    }
+}
+
+/// Whether `for_cell_type` can move on `board`: the position is one real play
+/// can reach, it is their turn and the game is not over yet.
+fn is_playable(board: &Board, for_cell_type: CellType) -> bool {
+    return board.validate_for(for_cell_type).is_ok()
+        && board.is_game_over() == GameOverType::PLAYING;
+}
+
+/// Lists the empty positions of `board`, winning and defending moves first so
+/// that the search can cut off the remaining ones early.
+fn get_ordered_moves(board: &Board, for_cell_type: CellType) -> Vec<usize> {
+    let mut winning_moves: Vec<usize> = vec![];
+    let mut defending_moves: Vec<usize> = vec![];
+
+    for line in board.lines() {
+        if let Some(position) = get_winning_position(board, for_cell_type, &line) {
+            winning_moves.push(position);
+        }
+
+        if let Some(position) = get_defending_position(board, for_cell_type, &line) {
+            defending_moves.push(position);
+        }
+    }
+
+    let mut moves: Vec<usize> = vec![];
+    let empty_positions = (0..board.len()).filter(|&position| board[position] == CellType::NON);
+    for position in winning_moves
+        .into_iter()
+        .chain(defending_moves)
+        .chain(empty_positions)
+    {
+        if !moves.contains(&position) {
+            moves.push(position);
+        }
+    }
+
+    return moves;
+}
+
+/// A move that completes a line for `for_cell_type`, or else one that stops
+/// the opponent from completing one. Every line is checked for a win before
+/// any block is considered.
+fn get_line_move(board: &Board, for_cell_type: CellType) -> Option<usize> {
+    if let Some(&position) = get_winning_positions(board, for_cell_type).first() {
+        return Some(position);
+    }
+    return get_blocking_position(board, for_cell_type);
+}
+
+/// Picks between the positions that stop the opponent from completing a line.
+/// When they cannot all be blocked, prefers the position closing the most
+/// opponent lines, then one that leaves `for_cell_type` a winning position of
+/// its own so that an opponent who misses the win can still be punished. Ties
+/// keep the first position found.
+fn get_blocking_position(board: &Board, for_cell_type: CellType) -> Option<usize> {
+    let opponent = for_cell_type.opponent();
+    let mut board = board.clone();
+    let mut best: Option<(usize, (usize, usize))> = None;
+
+    for position in get_winning_positions(&board, opponent) {
+        let blocked_lines = board
+            .lines()
+            .filter(|line| get_winning_position(&board, opponent, line) == Some(position))
+            .count();
+
+        board[position] = for_cell_type;
+        let threats = get_winning_positions(&board, for_cell_type).len();
+        board[position] = CellType::NON;
+
+        let rank = (blocked_lines, threats);
+        if best.is_none_or(|(_, best_rank)| rank > best_rank) {
+            best = Some((position, rank));
+        }
+    }
+
+    return best.map(|(position, _)| position);
+}
+
+/// Every empty position that would complete a line for `for_cell_type`.
+fn get_winning_positions(board: &Board, for_cell_type: CellType) -> Vec<usize> {
+    let mut positions: Vec<usize> = vec![];
+    for line in board.lines() {
+        if let Some(position) = get_winning_position(board, for_cell_type, &line) {
+            if !positions.contains(&position) {
+                positions.push(position);
+            }
+        }
+    }
+    return positions;
+}
+
+/// Every empty position that would give `for_cell_type` two or more winning
+/// positions at once.
+fn get_fork_positions(board: &Board, for_cell_type: CellType) -> Vec<usize> {
+    let mut board = board.clone();
+    let mut positions: Vec<usize> = vec![];
+
+    for position in 0..board.len() {
+        if board[position] != CellType::NON {
+            continue;
+        }
+
+        board[position] = for_cell_type;
+        if get_winning_positions(&board, for_cell_type).len() >= 2 {
+            positions.push(position);
+        }
+        board[position] = CellType::NON;
+    }
+
+    return positions;
+}
+
+fn get_winning_position(board: &Board, for_cell_type: CellType, line: &Line) -> Option<usize> {
     let mut selected_position = None;
-    let mut cell_type_match_count: u8 = 0;
+    let mut cell_type_match_count: usize = 0;
 
-    for position in positions {
+    for position in line.positions() {
         if board[position] != for_cell_type && board[position] != CellType::NON {
             return None;
         }
@@ -73,21 +888,17 @@ fn get_winning_position(
         }
     }
 
-    if cell_type_match_count != 2 {
+    if cell_type_match_count != line.length - 1 {
         return None;
     }
     return selected_position;
 }
 
-fn get_defending_position(
-    board: &Vec<CellType>,
-    for_cell_type: CellType,
-    positions: [usize; 3],
-) -> Option<usize> {
+fn get_defending_position(board: &Board, for_cell_type: CellType, line: &Line) -> Option<usize> {
     let mut selected_position = None;
-    let mut cell_type_match_count: u8 = 0;
+    let mut cell_type_match_count: usize = 0;
 
-    for position in positions {
+    for position in line.positions() {
         if board[position] == for_cell_type {
             return None;
         }
@@ -102,13 +913,8 @@ fn get_defending_position(
         }
     }
 
-    if cell_type_match_count != 2 {
+    if cell_type_match_count != line.length - 1 {
         return None;
     }
     return selected_position;
 }
-fn position_to_coord(position: &usize) -> board::Coord {
-    let x: isize = (*position as isize) % 3;
-    let y: isize = (*position as isize) / 3;
-    return board::Coord { x, y };
-}
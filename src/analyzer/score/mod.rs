@@ -0,0 +1,60 @@
+mod score_tests;
+
+use crate::analyzer::SCORE_WIN;
+use std::cmp::Ordering;
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    WIN,
+    DRAW,
+    LOSS,
+}
+
+/// How a move ends with best play from both sides. `plies` counts the moves
+/// of both players, this one included, until the winning line is completed;
+/// it is 0 for draws.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoveScore {
+    pub outcome: Outcome,
+    pub plies: u32,
+}
+
+impl MoveScore {
+    /// Converts a search score, where a win on the n-th ply is worth
+    /// `SCORE_WIN - n`.
+    pub fn from_score(score: i32) -> MoveScore {
+        let plies = (SCORE_WIN - score.abs()) as u32;
+        match score {
+            score if score > 0 => MoveScore {
+                outcome: Outcome::WIN,
+                plies,
+            },
+            score if score < 0 => MoveScore {
+                outcome: Outcome::LOSS,
+                plies,
+            },
+            _ => MoveScore {
+                outcome: Outcome::DRAW,
+                plies: 0,
+            },
+        }
+    }
+
+    /// The search score this was built from. Higher is better.
+    pub fn score(&self) -> i32 {
+        match self.outcome {
+            Outcome::WIN => SCORE_WIN - self.plies as i32,
+            Outcome::LOSS => -(SCORE_WIN - self.plies as i32),
+            Outcome::DRAW => 0,
+        }
+    }
+}
+
+/// Better moves compare greater: quicker wins, then draws, then slower
+/// losses.
+impl PartialOrd for MoveScore {
+    fn partial_cmp(&self, other: &MoveScore) -> Option<Ordering> {
+        return self.score().partial_cmp(&other.score());
+    }
+}
@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod score_tests {
+    use crate::analyzer::score::{MoveScore, Outcome};
+    use crate::analyzer::{self, Analyzer, Difficulty};
+    use crate::board::{self, CellType, Coord, Game, GameOverType};
+    use std::collections::HashSet;
+
+    #[test]
+    fn should_convert_search_scores() {
+        assert_eq!(
+            MoveScore::from_score(999),
+            MoveScore {
+                outcome: Outcome::WIN,
+                plies: 1
+            }
+        );
+        assert_eq!(
+            MoveScore::from_score(-996),
+            MoveScore {
+                outcome: Outcome::LOSS,
+                plies: 4
+            }
+        );
+        assert_eq!(
+            MoveScore::from_score(0),
+            MoveScore {
+                outcome: Outcome::DRAW,
+                plies: 0
+            }
+        );
+        assert_eq!(MoveScore::from_score(-996).score(), -996);
+    }
+
+    #[test]
+    fn should_order_quick_wins_before_draws_before_slow_losses() {
+        let quick_win = MoveScore::from_score(999);
+        let slow_win = MoveScore::from_score(995);
+        let draw = MoveScore::from_score(0);
+        let slow_loss = MoveScore::from_score(-996);
+        let quick_loss = MoveScore::from_score(-998);
+
+        assert!(quick_win > slow_win);
+        assert!(slow_win > draw);
+        assert!(draw > slow_loss);
+        assert!(slow_loss > quick_loss);
+    }
+
+    #[test]
+    fn should_score_every_empty_cell() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+        let game_board: Box<dyn Game> = Box::new("XX./OO./...".parse::<board::Board>().unwrap());
+
+        let scores = game_analyzer.score_moves(game_board, CellType::PLAYER_2);
+
+        let coords: Vec<Coord> = scores.iter().map(|(coord, _)| *coord).collect();
+        assert_eq!(
+            coords,
+            vec![
+                Coord { x: 2, y: 0 },
+                Coord { x: 2, y: 1 },
+                Coord { x: 0, y: 2 },
+                Coord { x: 1, y: 2 },
+                Coord { x: 2, y: 2 },
+            ]
+        );
+        assert_eq!(scores[1].1, MoveScore::from_score(999));
+        assert_eq!(
+            scores[0].1,
+            MoveScore {
+                outcome: Outcome::WIN,
+                plies: 3
+            }
+        );
+        assert_eq!(
+            scores[2].1,
+            MoveScore {
+                outcome: Outcome::LOSS,
+                plies: 2
+            }
+        );
+    }
+
+    fn assert_best_move_has_best_score(
+        game_board: Box<dyn Game>,
+        to_move: CellType,
+        game_analyzer: &analyzer::AnalyzerState,
+        visited: &mut HashSet<String>,
+    ) {
+        let board_state = game_board.get_board_state();
+        if game_board.is_game_over() != GameOverType::PLAYING
+            || !visited.insert(board_state.to_string())
+        {
+            return;
+        }
+
+        let scores = game_analyzer.score_moves(Box::new(board_state.clone()), to_move);
+        let best_move = game_analyzer
+            .get_best_move(Box::new(board_state.clone()), to_move)
+            .unwrap();
+        let best_score = scores
+            .iter()
+            .find(|(coord, _)| *coord == best_move)
+            .map(|(_, score)| *score)
+            .unwrap();
+
+        for (coord, score) in scores {
+            assert!(score <= best_score);
+            assert_best_move_has_best_score(
+                game_board.set_player(coord, to_move),
+                to_move.opponent(),
+                game_analyzer,
+                visited,
+            );
+        }
+    }
+
+    #[test]
+    fn should_agree_with_get_best_move_on_every_reachable_position() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+        let mut visited = HashSet::new();
+
+        assert_best_move_has_best_score(
+            board::new(),
+            CellType::PLAYER_1,
+            &game_analyzer,
+            &mut visited,
+        );
+
+        assert_eq!(visited.len(), 4520);
+    }
+}
@@ -0,0 +1,221 @@
+mod transposition_tests;
+
+use crate::board::symmetry::{self, Symmetry};
+use crate::board::{Board, CellType};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+/// Entries kept before the table is emptied again, so that long searches on
+/// large boards cannot grow it without bound.
+const MAX_ENTRIES: usize = 1 << 20;
+
+/// Locks a `SharedTranspositionTable` splits its entries over, so that
+/// threads searching different positions rarely wait for each other.
+const SHARDS: usize = 64;
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bound {
+    EXACT,
+    LOWER,
+    UPPER,
+}
+
+/// A search result for one position. `best_move` is a position on the board
+/// that was looked up, whatever orientation it was stored in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Entry {
+    pub value: i32,
+    pub bound: Bound,
+    pub best_move: Option<usize>,
+    pub depth: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Statistics {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct PositionKey {
+    width: usize,
+    height: usize,
+    win_length: usize,
+    to_move: i8,
+    cells: Vec<i8>,
+}
+
+/// Cache of search results keyed by the canonical orientation of a position,
+/// so that all rotations and reflections of a board share one entry.
+#[derive(Debug)]
+pub struct TranspositionTable {
+    entries: HashMap<PositionKey, Entry>,
+    max_entries: usize,
+    hits: u64,
+    misses: u64,
+}
+
+impl Default for TranspositionTable {
+    fn default() -> TranspositionTable {
+        return TranspositionTable::with_max_entries(MAX_ENTRIES);
+    }
+}
+
+impl TranspositionTable {
+    pub fn new() -> TranspositionTable {
+        return TranspositionTable::default();
+    }
+
+    fn with_max_entries(max_entries: usize) -> TranspositionTable {
+        return TranspositionTable {
+            entries: HashMap::new(),
+            max_entries,
+            hits: 0,
+            misses: 0,
+        };
+    }
+
+    pub fn get(&mut self, board: &Board, to_move: CellType) -> Option<Entry> {
+        let (key, symmetry) = canonical_key(board, to_move);
+        return self.get_by_key(&key, symmetry, board);
+    }
+
+    pub fn insert(&mut self, board: &Board, to_move: CellType, entry: Entry) {
+        let (key, symmetry) = canonical_key(board, to_move);
+        self.insert_by_key(key, symmetry, board, entry);
+    }
+
+    pub fn statistics(&self) -> Statistics {
+        return Statistics {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.entries.len(),
+        };
+    }
+
+    pub fn clear(&mut self) {
+        *self = TranspositionTable::with_max_entries(self.max_entries);
+    }
+
+    fn get_by_key(
+        &mut self,
+        key: &PositionKey,
+        symmetry: Symmetry,
+        board: &Board,
+    ) -> Option<Entry> {
+        match self.entries.get(key) {
+            Some(entry) => {
+                self.hits += 1;
+                let mut entry = *entry;
+                entry.best_move = entry
+                    .best_move
+                    .map(|position| symmetry.inverse().apply(board, position));
+                return Some(entry);
+            }
+            None => {
+                self.misses += 1;
+                return None;
+            }
+        }
+    }
+
+    fn insert_by_key(
+        &mut self,
+        key: PositionKey,
+        symmetry: Symmetry,
+        board: &Board,
+        mut entry: Entry,
+    ) {
+        if self.entries.len() >= self.max_entries {
+            self.entries.clear();
+        }
+
+        entry.best_move = entry
+            .best_move
+            .map(|position| symmetry.apply(board, position));
+        self.entries.insert(key, entry);
+    }
+}
+
+/// A `TranspositionTable` that threads can share. Positions are spread over
+/// several tables by their key, each behind its own lock, and a lock is only
+/// held for a single lookup or store.
+#[derive(Debug)]
+pub struct SharedTranspositionTable {
+    shards: Vec<Mutex<TranspositionTable>>,
+}
+
+impl Default for SharedTranspositionTable {
+    fn default() -> SharedTranspositionTable {
+        return SharedTranspositionTable {
+            shards: (0..SHARDS)
+                .map(|_| Mutex::new(TranspositionTable::with_max_entries(MAX_ENTRIES / SHARDS)))
+                .collect(),
+        };
+    }
+}
+
+impl SharedTranspositionTable {
+    pub fn new() -> SharedTranspositionTable {
+        return SharedTranspositionTable::default();
+    }
+
+    pub fn get(&self, board: &Board, to_move: CellType) -> Option<Entry> {
+        let (key, symmetry) = canonical_key(board, to_move);
+        return self
+            .shard(&key)
+            .lock()
+            .unwrap()
+            .get_by_key(&key, symmetry, board);
+    }
+
+    pub fn insert(&self, board: &Board, to_move: CellType, entry: Entry) {
+        let (key, symmetry) = canonical_key(board, to_move);
+        self.shard(&key)
+            .lock()
+            .unwrap()
+            .insert_by_key(key, symmetry, board, entry);
+    }
+
+    /// Counts summed over every shard.
+    pub fn statistics(&self) -> Statistics {
+        let mut statistics = Statistics::default();
+        for shard in &self.shards {
+            let shard_statistics = shard.lock().unwrap().statistics();
+            statistics.hits += shard_statistics.hits;
+            statistics.misses += shard_statistics.misses;
+            statistics.entries += shard_statistics.entries;
+        }
+        return statistics;
+    }
+
+    pub fn clear(&self) {
+        for shard in &self.shards {
+            shard.lock().unwrap().clear();
+        }
+    }
+
+    fn shard(&self, key: &PositionKey) -> &Mutex<TranspositionTable> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        return &self.shards[hasher.finish() as usize % SHARDS];
+    }
+}
+
+/// The smallest encoding of `board` over all of its symmetries, together with
+/// the symmetry that produces it.
+fn canonical_key(board: &Board, to_move: CellType) -> (PositionKey, Symmetry) {
+    let (cells, symmetry) = symmetry::canonical_cells(board);
+    let key = PositionKey {
+        width: board.width,
+        height: board.height,
+        win_length: board.win_length,
+        to_move: to_move as i8,
+        cells,
+    };
+    return (key, symmetry);
+}
@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod transposition_tests {
+    use crate::analyzer::transposition::{
+        Bound, Entry, SharedTranspositionTable, Statistics, TranspositionTable,
+    };
+    use crate::analyzer::{self, Analyzer, Difficulty};
+    use crate::board::{self, Board, CellType, Coord};
+
+    fn board_with(marks: &[(Coord, CellType)]) -> Board {
+        let mut game_board = board::new();
+        for (coord, cell_type) in marks {
+            game_board = game_board.set_player(*coord, *cell_type);
+        }
+        return game_board.get_board_state();
+    }
+
+    fn entry_with_best_move(best_move: usize) -> Entry {
+        return Entry {
+            value: 0,
+            bound: Bound::EXACT,
+            best_move: Some(best_move),
+            depth: 7,
+        };
+    }
+
+    #[test]
+    fn should_share_entry_between_rotations_and_reflections() {
+        let mut cache = TranspositionTable::new();
+        let board = board_with(&[
+            (Coord { x: 0, y: 0 }, CellType::PLAYER_1),
+            (Coord { x: 1, y: 0 }, CellType::PLAYER_2),
+        ]);
+        cache.insert(&board, CellType::PLAYER_1, entry_with_best_move(4));
+
+        let rotated = board_with(&[
+            (Coord { x: 2, y: 0 }, CellType::PLAYER_1),
+            (Coord { x: 2, y: 1 }, CellType::PLAYER_2),
+        ]);
+        let mirrored = board_with(&[
+            (Coord { x: 0, y: 0 }, CellType::PLAYER_1),
+            (Coord { x: 0, y: 1 }, CellType::PLAYER_2),
+        ]);
+
+        assert!(cache.get(&rotated, CellType::PLAYER_1).is_some());
+        assert!(cache.get(&mirrored, CellType::PLAYER_1).is_some());
+        assert_eq!(cache.statistics().entries, 1);
+    }
+
+    #[test]
+    fn should_map_best_move_back_to_looked_up_orientation() {
+        let mut cache = TranspositionTable::new();
+        let board = board_with(&[(Coord { x: 0, y: 0 }, CellType::PLAYER_1)]);
+        cache.insert(&board, CellType::PLAYER_2, entry_with_best_move(1));
+
+        let rotated = board_with(&[(Coord { x: 2, y: 2 }, CellType::PLAYER_1)]);
+        let entry = cache.get(&rotated, CellType::PLAYER_2).unwrap();
+
+        // Rotating by 180 degrees takes the cell right of the corner to the
+        // cell left of the opposite corner.
+        assert_eq!(entry.best_move, Some(7));
+    }
+
+    #[test]
+    fn should_keep_side_to_move_and_board_size_apart() {
+        let mut cache = TranspositionTable::new();
+        let board = board_with(&[(Coord { x: 1, y: 1 }, CellType::PLAYER_1)]);
+        cache.insert(&board, CellType::PLAYER_2, entry_with_best_move(0));
+
+        assert_eq!(cache.get(&board, CellType::PLAYER_1), None);
+        assert_eq!(cache.get(&Board::new(3, 3, 2), CellType::PLAYER_2), None);
+    }
+
+    #[test]
+    fn should_count_hits_and_misses() {
+        let mut cache = TranspositionTable::new();
+        let board = board_with(&[]);
+
+        cache.get(&board, CellType::PLAYER_1);
+        cache.insert(&board, CellType::PLAYER_1, entry_with_best_move(4));
+        cache.get(&board, CellType::PLAYER_1);
+        cache.get(&board, CellType::PLAYER_1);
+
+        assert_eq!(
+            cache.statistics(),
+            Statistics {
+                hits: 2,
+                misses: 1,
+                entries: 1,
+            }
+        );
+
+        cache.clear();
+
+        assert_eq!(cache.statistics(), Statistics::default());
+    }
+
+    #[test]
+    fn should_share_entries_and_counts_across_shards() {
+        let cache = SharedTranspositionTable::new();
+        let corner = board_with(&[(Coord { x: 0, y: 0 }, CellType::PLAYER_1)]);
+        let center = board_with(&[(Coord { x: 1, y: 1 }, CellType::PLAYER_1)]);
+        cache.insert(&corner, CellType::PLAYER_2, entry_with_best_move(1));
+        cache.insert(&center, CellType::PLAYER_2, entry_with_best_move(0));
+
+        let rotated = board_with(&[(Coord { x: 2, y: 2 }, CellType::PLAYER_1)]);
+        assert_eq!(
+            cache.get(&rotated, CellType::PLAYER_2).unwrap().best_move,
+            Some(7)
+        );
+        assert!(cache.get(&center, CellType::PLAYER_2).is_some());
+        assert_eq!(cache.get(&center, CellType::PLAYER_1), None);
+        assert_eq!(
+            cache.statistics(),
+            Statistics {
+                hits: 2,
+                misses: 1,
+                entries: 2,
+            }
+        );
+
+        cache.clear();
+
+        assert_eq!(cache.statistics(), Statistics::default());
+    }
+
+    #[test]
+    fn should_hit_cache_when_analyzer_searches_again() {
+        let game_analyzer = analyzer::new(Difficulty::PERFECT);
+
+        game_analyzer.get_best_move(board::new(), CellType::PLAYER_1);
+        let first_search = game_analyzer.cache_statistics();
+        game_analyzer.get_best_move(board::new(), CellType::PLAYER_1);
+        let second_search = game_analyzer.cache_statistics();
+
+        assert!(first_search.hits > 0);
+        assert!(first_search.entries > 0);
+        assert_eq!(second_search.misses, first_search.misses);
+        assert!(second_search.hits > first_search.hits);
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod arena_tests {
+    use crate::analyzer::{self, book, heuristic, Analyzer, Difficulty};
+    use crate::arena::{self, MatchResult, Openings, Record};
+    use crate::board::{self, CellType, Coord, Game, GameOverType, GameState};
+
+    /// Always answers with the same cell, whether it is free or not.
+    struct StubbornAnalyzer {}
+
+    impl Analyzer for StubbornAnalyzer {
+        fn get_best_move(
+            &self,
+            _game_board: Box<dyn board::Game>,
+            _for_cell_type: CellType,
+        ) -> Option<Coord> {
+            return Some(Coord { x: 0, y: 0 });
+        }
+    }
+
+    #[test]
+    fn given_two_perfect_analyzers_then_draw_every_game() {
+        let result = arena::play_match(
+            &analyzer::new(Difficulty::PERFECT),
+            &book::new(),
+            20,
+            &Openings::EMPTY,
+        );
+
+        assert_eq!(
+            result.total(),
+            Record {
+                wins: 0,
+                draws: 20,
+                losses: 0
+            }
+        );
+        assert_eq!(result.as_player_1.games(), 10);
+        assert_eq!(result.as_player_2.games(), 10);
+    }
+
+    #[test]
+    fn given_random_openings_then_perfect_analyzer_never_loses() {
+        let result = arena::play_match(
+            &analyzer::new(Difficulty::PERFECT),
+            &analyzer::new_with_seed(Difficulty::RANDOM, 7),
+            200,
+            // Any first move still leaves a draw for both sides.
+            &Openings::RANDOM { plies: 1, seed: 7 },
+        );
+
+        assert_eq!(result.total().games(), 200);
+        assert_eq!(result.total().losses, 0);
+        assert!(result.total().wins > 100);
+    }
+
+    #[test]
+    fn given_same_seed_then_play_same_openings() {
+        let openings = Openings::RANDOM { plies: 3, seed: 11 };
+
+        let result = arena::play_match(&heuristic::new(), &book::new(), 50, &openings);
+        let replayed_result = arena::play_match(&heuristic::new(), &book::new(), 50, &openings);
+
+        assert_eq!(result, replayed_result);
+    }
+
+    #[test]
+    fn given_listed_openings_then_start_from_them() {
+        let lost_opening: GameState = "XX./OO./... x".parse().unwrap();
+
+        let result = arena::play_match(
+            &analyzer::new(Difficulty::PERFECT),
+            &analyzer::new(Difficulty::PERFECT),
+            4,
+            &Openings::LISTED(vec![lost_opening]),
+        );
+
+        // X to move wins at once, whichever analyzer plays it.
+        assert_eq!(result.as_player_1.wins, 2);
+        assert_eq!(result.as_player_2.losses, 2);
+    }
+
+    #[test]
+    fn given_illegal_move_then_forfeit_game() {
+        let game_state = GameState::new(CellType::PLAYER_1);
+
+        let record = arena::play_game(&StubbornAnalyzer {}, &StubbornAnalyzer {}, &game_state);
+
+        assert_eq!(record.result, GameOverType::PLAYER_1_WIN);
+        assert!(record.forfeit);
+        assert_eq!(
+            record.moves,
+            vec![(Coord { x: 0, y: 0 }, CellType::PLAYER_1)]
+        );
+        assert_eq!(
+            record.replay().unwrap().is_game_over(),
+            GameOverType::PLAYING
+        );
+    }
+
+    #[test]
+    fn given_finished_game_then_not_a_forfeit() {
+        let perfect = analyzer::new(Difficulty::PERFECT);
+
+        let record = arena::play_game(&perfect, &perfect, &GameState::new(CellType::PLAYER_1));
+
+        assert!(!record.forfeit);
+        assert_eq!(record.replay().unwrap().is_game_over(), record.result);
+    }
+
+    #[test]
+    #[should_panic(expected = "a match needs at least one listed opening")]
+    fn given_no_listed_openings_then_refuse_to_play() {
+        let perfect = analyzer::new(Difficulty::PERFECT);
+
+        arena::play_match(&perfect, &perfect, 2, &Openings::LISTED(vec![]));
+    }
+
+    #[test]
+    fn should_print_result_table() {
+        let result = MatchResult {
+            as_player_1: Record {
+                wins: 3,
+                draws: 1,
+                losses: 0,
+            },
+            as_player_2: Record {
+                wins: 1,
+                draws: 2,
+                losses: 1,
+            },
+        };
+
+        assert_eq!(
+            result.to_string(),
+            "         Wins  Draws Losses\n\
+             As X        3      1      0\n\
+             As O        1      2      1\n\
+             Total       4      3      1\n"
+        );
+    }
+}
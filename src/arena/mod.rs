@@ -0,0 +1,187 @@
+//! Matches between two analyzers, to compare bot variants over many games.
+
+mod arena_tests;
+pub mod tournament;
+
+use crate::analyzer::Analyzer;
+use crate::board::{CellType, Coord, Game, GameOverType, GameRecord, GameState};
+use crate::random::Random;
+use std::fmt;
+
+/// Where the games of a match start.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Openings {
+    /// Every game starts on the empty 3x3 board with X to move.
+    EMPTY,
+    /// Each pair of games starts after up to `plies` random moves on the 3x3
+    /// board. Moves that would end the game are never picked. The same seed
+    /// gives the same openings.
+    RANDOM { plies: usize, seed: u64 },
+    /// The pairs of games go through these positions in turn.
+    LISTED(Vec<GameState>),
+}
+
+/// Games won, drawn and lost by one side of a match.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Record {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+impl Record {
+    pub fn games(&self) -> usize {
+        return self.wins + self.draws + self.losses;
+    }
+
+    /// Counts a game that ended with `result`, played as `cell_type`.
+    fn add(&mut self, result: GameOverType, cell_type: CellType) {
+        match (result, cell_type) {
+            (GameOverType::PLAYER_1_WIN, CellType::PLAYER_1)
+            | (GameOverType::PLAYER_2_WIN, CellType::PLAYER_2) => self.wins += 1,
+            (GameOverType::PLAYER_1_WIN, _) | (GameOverType::PLAYER_2_WIN, _) => self.losses += 1,
+            _ => self.draws += 1,
+        }
+    }
+}
+
+/// Results of a match from the point of view of the first analyzer.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MatchResult {
+    /// Games the first analyzer played as X.
+    pub as_player_1: Record,
+    /// Games the first analyzer played as O.
+    pub as_player_2: Record,
+}
+
+impl MatchResult {
+    pub fn total(&self) -> Record {
+        return Record {
+            wins: self.as_player_1.wins + self.as_player_2.wins,
+            draws: self.as_player_1.draws + self.as_player_2.draws,
+            losses: self.as_player_1.losses + self.as_player_2.losses,
+        };
+    }
+}
+
+impl fmt::Display for MatchResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "       {:>6} {:>6} {:>6}", "Wins", "Draws", "Losses")?;
+        for (name, record) in [
+            ("As X", self.as_player_1),
+            ("As O", self.as_player_2),
+            ("Total", self.total()),
+        ] {
+            writeln!(
+                f,
+                "{:<6} {:>6} {:>6} {:>6}",
+                name, record.wins, record.draws, record.losses
+            )?;
+        }
+        return Ok(());
+    }
+}
+
+/// Plays `games` games between `first` and `second`. Games come in pairs
+/// from the same opening, with the analyzers swapping sides, so that neither
+/// gets the better half of an opening.
+///
+/// Panics if `openings` lists no positions.
+pub fn play_match(
+    first: &dyn Analyzer,
+    second: &dyn Analyzer,
+    games: usize,
+    openings: &Openings,
+) -> MatchResult {
+    assert!(
+        openings != &Openings::LISTED(vec![]),
+        "a match needs at least one listed opening"
+    );
+
+    let mut random = match openings {
+        Openings::RANDOM { seed, .. } => Random::new(*seed),
+        _ => Random::new(0),
+    };
+    let mut result = MatchResult::default();
+    let mut opening = GameState::new(CellType::PLAYER_1);
+
+    for game in 0..games {
+        if game % 2 == 0 {
+            opening = match openings {
+                Openings::EMPTY => GameState::new(CellType::PLAYER_1),
+                Openings::RANDOM { plies, .. } => random_opening(&mut random, *plies),
+                Openings::LISTED(positions) => positions[(game / 2) % positions.len()].clone(),
+            };
+
+            let record = play_game(first, second, &opening);
+            result.as_player_1.add(record.result, CellType::PLAYER_1);
+        } else {
+            let record = play_game(second, first, &opening);
+            result.as_player_2.add(record.result, CellType::PLAYER_2);
+        }
+    }
+
+    return result;
+}
+
+/// Plays one game from `start` until it ends. A player that returns no move,
+/// or a move that is not legal, loses the game on the spot, and the record
+/// is marked as a forfeit.
+pub fn play_game(
+    player_1: &dyn Analyzer,
+    player_2: &dyn Analyzer,
+    start: &GameState,
+) -> GameRecord {
+    let mut game_state = start.clone();
+
+    while game_state.is_game_over() == GameOverType::PLAYING {
+        let to_move = game_state.side_to_move();
+        let analyzer = match to_move {
+            CellType::PLAYER_1 => player_1,
+            _ => player_2,
+        };
+
+        let played = analyzer
+            .get_best_move(Box::new(game_state.clone()), to_move)
+            .map(|coord| game_state.play(coord));
+        if played != Some(Ok(())) {
+            let mut record = game_state.record();
+            record.result = match to_move {
+                CellType::PLAYER_1 => GameOverType::PLAYER_2_WIN,
+                _ => GameOverType::PLAYER_1_WIN,
+            };
+            record.forfeit = true;
+            return record;
+        }
+    }
+
+    return game_state.record();
+}
+
+fn random_opening(random: &mut Random, plies: usize) -> GameState {
+    let mut game_state = GameState::new(CellType::PLAYER_1);
+
+    for _ in 0..plies {
+        let board_state = game_state.get_board_state();
+        let to_move = game_state.side_to_move();
+        let quiet_moves: Vec<Coord> = (0..board_state.len())
+            .filter(|&position| board_state[position] == CellType::NON)
+            .map(|position| board_state.coord_of(position))
+            .filter(|coord| {
+                game_state
+                    .try_set_player(*coord, to_move)
+                    .map(|next| next.is_game_over() == GameOverType::PLAYING)
+                    .unwrap_or(false)
+            })
+            .collect();
+        if quiet_moves.is_empty() {
+            break;
+        }
+
+        let coord = quiet_moves[random.next_below(quiet_moves.len())];
+        game_state.play(coord).expect("only empty cells are picked");
+    }
+
+    return game_state;
+}
@@ -0,0 +1,272 @@
+//! Round-robin tournaments between named analyzers, with Elo ratings kept
+//! from one run to the next in a plain text file.
+
+mod tournament_tests;
+
+use crate::analyzer::Analyzer;
+use crate::arena::{self, Openings, Record};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+use std::{error, fmt};
+
+/// Rating of a bot that has not played yet.
+pub const INITIAL_RATING: f64 = 1500.0;
+
+/// How far one game can move a rating.
+const K_FACTOR: f64 = 32.0;
+
+/// The Elo rating of one bot and every game it played for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rating {
+    pub name: String,
+    pub elo: f64,
+    pub record: Record,
+}
+
+/// Ratings of every bot that has played, best first.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Leaderboard {
+    ratings: Vec<Rating>,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug)]
+pub enum LeaderboardError {
+    IO(io::Error),
+    /// A line of the file could not be read. Carries its line number.
+    INVALID_LINE(usize),
+}
+
+impl fmt::Display for LeaderboardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LeaderboardError::IO(error) => write!(f, "{}", error),
+            LeaderboardError::INVALID_LINE(line) => {
+                write!(
+                    f,
+                    "line {} is not `<name> <elo> <wins> <draws> <losses>`",
+                    line
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for LeaderboardError {}
+
+impl From<io::Error> for LeaderboardError {
+    fn from(error: io::Error) -> LeaderboardError {
+        return LeaderboardError::IO(error);
+    }
+}
+
+/// Why a tournament could not start.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum TournamentError {
+    /// Two entrants share this name, so their ratings would be merged.
+    DUPLICATE_NAME(String),
+}
+
+impl fmt::Display for TournamentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TournamentError::DUPLICATE_NAME(name) => {
+                write!(f, "more than one bot is named `{}`", name)
+            }
+        }
+    }
+}
+
+impl error::Error for TournamentError {}
+
+impl Leaderboard {
+    pub fn new() -> Leaderboard {
+        return Leaderboard::default();
+    }
+
+    /// Reads a leaderboard written by `save`. A missing file is an empty
+    /// leaderboard, so that the first tournament can create it.
+    pub fn load(path: &Path) -> Result<Leaderboard, LeaderboardError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Leaderboard::new()),
+            Err(error) => return Err(LeaderboardError::IO(error)),
+        };
+
+        let mut leaderboard = Leaderboard::new();
+        for (index, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let rating = parse_rating(line).ok_or(LeaderboardError::INVALID_LINE(index + 1))?;
+            leaderboard.ratings.push(rating);
+        }
+        leaderboard.sort();
+        return Ok(leaderboard);
+    }
+
+    /// Writes one bot per line: its name, rating, wins, draws and losses,
+    /// separated by tabs.
+    pub fn save(&self, path: &Path) -> Result<(), LeaderboardError> {
+        let lines: Vec<String> = self
+            .ratings
+            .iter()
+            .map(|rating| {
+                format!(
+                    "{}\t{}\t{}\t{}\t{}\n",
+                    rating.name,
+                    rating.elo,
+                    rating.record.wins,
+                    rating.record.draws,
+                    rating.record.losses
+                )
+            })
+            .collect();
+        fs::write(path, lines.concat())?;
+        return Ok(());
+    }
+
+    /// Best first.
+    pub fn ratings(&self) -> &[Rating] {
+        return &self.ratings;
+    }
+
+    pub fn rating(&self, name: &str) -> Option<&Rating> {
+        return self.ratings.iter().find(|rating| rating.name == name);
+    }
+
+    /// Updates both ratings after `name` played `opponent`. Each game of the
+    /// match is rated as if it had scored the match average, so the order of
+    /// the games does not matter.
+    pub fn record_match(&mut self, name: &str, opponent: &str, record: &Record) {
+        let mut elo = self.rating_mut(name).elo;
+        let mut opponent_elo = self.rating_mut(opponent).elo;
+
+        let score = (record.wins as f64 + record.draws as f64 / 2.0) / record.games() as f64;
+        for _ in 0..record.games() {
+            let expected = 1.0 / (1.0 + 10f64.powf((opponent_elo - elo) / 400.0));
+            let change = K_FACTOR * (score - expected);
+            elo += change;
+            opponent_elo -= change;
+        }
+
+        let rating = self.rating_mut(name);
+        rating.elo = elo;
+        add_games(&mut rating.record, record);
+
+        let opponent_rating = self.rating_mut(opponent);
+        opponent_rating.elo = opponent_elo;
+        add_games(
+            &mut opponent_rating.record,
+            &Record {
+                wins: record.losses,
+                draws: record.draws,
+                losses: record.wins,
+            },
+        );
+
+        self.sort();
+    }
+
+    fn rating_mut(&mut self, name: &str) -> &mut Rating {
+        let index = match self.ratings.iter().position(|rating| rating.name == name) {
+            Some(index) => index,
+            None => {
+                self.ratings.push(Rating {
+                    name: name.to_string(),
+                    elo: INITIAL_RATING,
+                    record: Record::default(),
+                });
+                self.ratings.len() - 1
+            }
+        };
+        return &mut self.ratings[index];
+    }
+
+    fn sort(&mut self) {
+        self.ratings
+            .sort_by(|rating, other| other.elo.total_cmp(&rating.elo));
+    }
+}
+
+impl fmt::Display for Leaderboard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self
+            .ratings
+            .iter()
+            .map(|rating| rating.name.len())
+            .chain(["Bot".len()])
+            .max()
+            .unwrap_or(0);
+
+        writeln!(
+            f,
+            "{:>4}  {:<width$} {:>6} {:>6} {:>6} {:>6}",
+            "#", "Bot", "Elo", "Wins", "Draws", "Losses"
+        )?;
+        for (index, rating) in self.ratings.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>4}  {:<width$} {:>6.0} {:>6} {:>6} {:>6}",
+                index + 1,
+                rating.name,
+                rating.elo,
+                rating.record.wins,
+                rating.record.draws,
+                rating.record.losses
+            )?;
+        }
+        return Ok(());
+    }
+}
+
+/// Plays a match of `games` games between every two of `bots` and records
+/// the results in `leaderboard`. Names are written to the leaderboard file as
+/// they are, so they must not contain tabs or line breaks. Ratings are kept
+/// by name, so no game is played when two bots share one.
+pub fn run_round_robin(
+    bots: &[(&str, &dyn Analyzer)],
+    games: usize,
+    openings: &Openings,
+    leaderboard: &mut Leaderboard,
+) -> Result<(), TournamentError> {
+    for (index, (name, _)) in bots.iter().enumerate() {
+        if bots[..index].iter().any(|(other, _)| other == name) {
+            return Err(TournamentError::DUPLICATE_NAME(name.to_string()));
+        }
+    }
+
+    for (index, (name, analyzer)) in bots.iter().enumerate() {
+        for (opponent, opponent_analyzer) in &bots[index + 1..] {
+            let result = arena::play_match(*analyzer, *opponent_analyzer, games, openings);
+            leaderboard.record_match(name, opponent, &result.total());
+        }
+    }
+    return Ok(());
+}
+
+fn add_games(record: &mut Record, other: &Record) {
+    record.wins += other.wins;
+    record.draws += other.draws;
+    record.losses += other.losses;
+}
+
+fn parse_rating(line: &str) -> Option<Rating> {
+    let fields: Vec<&str> = line.split('\t').collect();
+    match fields[..] {
+        [name, elo, wins, draws, losses] => {
+            return Some(Rating {
+                name: name.to_string(),
+                elo: elo.parse().ok()?,
+                record: Record {
+                    wins: wins.parse().ok()?,
+                    draws: draws.parse().ok()?,
+                    losses: losses.parse().ok()?,
+                },
+            })
+        }
+        _ => return None,
+    }
+}
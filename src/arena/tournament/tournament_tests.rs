@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tournament_tests {
+    use crate::analyzer::{self, book, Analyzer, Difficulty};
+    use crate::arena::tournament::{
+        self, Leaderboard, LeaderboardError, TournamentError, INITIAL_RATING,
+    };
+    use crate::arena::{Openings, Record};
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn temp_path(name: &str) -> PathBuf {
+        return env::temp_dir().join(format!("tictactoe-{}-{}.txt", name, std::process::id()));
+    }
+
+    #[test]
+    fn given_equal_ratings_then_move_by_half_k_factor_per_win() {
+        let mut leaderboard = Leaderboard::new();
+
+        leaderboard.record_match(
+            "winner",
+            "loser",
+            &Record {
+                wins: 1,
+                draws: 0,
+                losses: 0,
+            },
+        );
+
+        assert_eq!(
+            leaderboard.rating("winner").unwrap().elo,
+            INITIAL_RATING + 16.0
+        );
+        assert_eq!(
+            leaderboard.rating("loser").unwrap().elo,
+            INITIAL_RATING - 16.0
+        );
+        assert_eq!(leaderboard.rating("loser").unwrap().record.losses, 1);
+        assert_eq!(leaderboard.ratings()[0].name, "winner");
+    }
+
+    #[test]
+    fn given_draws_between_equal_ratings_then_keep_ratings() {
+        let mut leaderboard = Leaderboard::new();
+
+        leaderboard.record_match(
+            "first",
+            "second",
+            &Record {
+                wins: 0,
+                draws: 10,
+                losses: 0,
+            },
+        );
+
+        assert_eq!(leaderboard.rating("first").unwrap().elo, INITIAL_RATING);
+        assert_eq!(leaderboard.rating("second").unwrap().record.draws, 10);
+    }
+
+    #[test]
+    fn given_round_robin_then_rank_random_last() {
+        let perfect = analyzer::new(Difficulty::PERFECT);
+        let opening_book = book::new();
+        let random = analyzer::new_with_seed(Difficulty::RANDOM, 5);
+        let bots: Vec<(&str, &dyn Analyzer)> = vec![
+            ("perfect", &perfect),
+            ("book", &opening_book),
+            ("random", &random),
+        ];
+        let mut leaderboard = Leaderboard::new();
+
+        tournament::run_round_robin(&bots, 20, &Openings::EMPTY, &mut leaderboard).unwrap();
+
+        assert_eq!(leaderboard.ratings().len(), 3);
+        assert_eq!(leaderboard.ratings()[2].name, "random");
+        assert_eq!(leaderboard.rating("random").unwrap().record.wins, 0);
+        assert_eq!(leaderboard.rating("perfect").unwrap().record.games(), 40);
+    }
+
+    #[test]
+    fn given_duplicate_names_then_refuse_to_play() {
+        let perfect = analyzer::new(Difficulty::PERFECT);
+        let random = analyzer::new_with_seed(Difficulty::RANDOM, 5);
+        let bots: Vec<(&str, &dyn Analyzer)> =
+            vec![("bot", &perfect), ("random", &random), ("bot", &random)];
+        let mut leaderboard = Leaderboard::new();
+
+        let result = tournament::run_round_robin(&bots, 2, &Openings::EMPTY, &mut leaderboard);
+
+        assert_eq!(
+            result,
+            Err(TournamentError::DUPLICATE_NAME(String::from("bot")))
+        );
+        assert!(leaderboard.ratings().is_empty());
+    }
+
+    #[test]
+    fn should_save_and_load_leaderboard() {
+        let path = temp_path("leaderboard");
+        let mut leaderboard = Leaderboard::new();
+        leaderboard.record_match(
+            "easy",
+            "medium",
+            &Record {
+                wins: 3,
+                draws: 4,
+                losses: 5,
+            },
+        );
+
+        leaderboard.save(&path).unwrap();
+        let loaded_leaderboard = Leaderboard::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded_leaderboard, leaderboard);
+    }
+
+    #[test]
+    fn given_missing_file_then_load_empty_leaderboard() {
+        let leaderboard = Leaderboard::load(&temp_path("missing")).unwrap();
+
+        assert!(leaderboard.ratings().is_empty());
+    }
+
+    #[test]
+    fn given_invalid_line_then_report_line_number() {
+        let path = temp_path("invalid");
+        fs::write(&path, "easy\t1500.0\t1\t2\t3\nmedium\tstrong\n").unwrap();
+
+        let result = Leaderboard::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(LeaderboardError::INVALID_LINE(2))));
+    }
+
+    #[test]
+    fn should_print_leaderboard() {
+        let mut leaderboard = Leaderboard::new();
+        leaderboard.record_match(
+            "perfect",
+            "random",
+            &Record {
+                wins: 1,
+                draws: 0,
+                losses: 0,
+            },
+        );
+
+        assert_eq!(
+            leaderboard.to_string(),
+            "   #  Bot        Elo   Wins  Draws Losses\n\
+             \x20  1  perfect   1516      1      0      0\n\
+             \x20  2  random    1484      0      0      1\n"
+        );
+    }
+}
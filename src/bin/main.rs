@@ -1,5 +1,513 @@
-use analyzer::analyzer as ANL;
+use analyzer::analyzer::mcts::{self, MctsConfig};
+use analyzer::analyzer::{self as ANL, book, heuristic, Analyzer, Difficulty};
+use analyzer::arena::tournament::{self, Leaderboard};
+use analyzer::arena::{self, Openings};
+use analyzer::board::{self, game_tree, CellType, Coord, Game, GameOverType, GameState};
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::process;
+
+const USAGE: &str =
+    "Usage: tictactoe_main [--mode <human-bot|bot-bot|human-human>] [--human <x|o>] [--first <x|o>]
+                      [--difficulty <random|easy|medium|perfect>]
+       tictactoe_main stats
+       tictactoe_main arena <bot> <bot> [--games <n>] [--plies <n>] [--seed <n>]
+       tictactoe_main tournament [<bot>...] [--games <n>] [--plies <n>] [--seed <n>] [--file <path>]
+
+Commands:
+  stats         play out every legal game from the empty board and print counts
+  arena         play two bots against each other and print their results; a bot
+                is random, easy, medium, perfect, heuristic, book or mcts. Games
+                start after --plies random moves (default: 0), --games times
+                (default: 100)
+  tournament    play every two bots (default: all of them) against each other,
+                update their Elo ratings in --file (default: leaderboard.tsv)
+                and print the leaderboard
+
+Options:
+  --mode        who plays the game (default: human-bot)
+  --human       side played by the human in human-bot mode (default: x)
+  --first       side that makes the first move (default: x)
+  --difficulty  strength of the bot (default: perfect)
+
+Enter moves as `<x> <y>` with both coordinates between 0 and 2. During the game,
+`undo` takes back your last move and the bot's reply, `redo` plays them again,
+`restart` starts a new game and `quit` leaves.";
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum PlayerKind {
+    HUMAN,
+    BOT,
+}
+
+/// What a human entered at the prompt.
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Command {
+    MOVE(Coord),
+    UNDO,
+    REDO,
+    RESTART,
+    QUIT,
+}
+
+#[derive(Debug)]
+struct Options {
+    player_1: PlayerKind,
+    player_2: PlayerKind,
+    first: CellType,
+    difficulty: Difficulty,
+}
+
+/// Why a command failed. Usage errors are printed with the usage text.
+#[allow(non_camel_case_types)]
+#[derive(Debug)]
+enum CommandError {
+    USAGE(String),
+    FAILURE(String),
+}
+
+impl From<String> for CommandError {
+    fn from(message: String) -> CommandError {
+        return CommandError::USAGE(message);
+    }
+}
 
 fn main() {
-  println!("");
-}
\ No newline at end of file
+    let result = match env::args().nth(1).as_deref() {
+        Some("stats") => {
+            print_statistics();
+            Ok(())
+        }
+        Some("arena") => run_arena(env::args().skip(2).collect()),
+        Some("tournament") => run_tournament(env::args().skip(2).collect()),
+        _ => run_game(env::args().skip(1).collect()),
+    };
+
+    match result {
+        Ok(()) => (),
+        Err(CommandError::USAGE(message)) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+        Err(CommandError::FAILURE(message)) => {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+    }
+}
+
+fn run_game(args: Vec<String>) -> Result<(), CommandError> {
+    let options = parse_options(args)?;
+
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    return play(&options, &mut stdin.lock(), &mut stdout.lock())
+        .map_err(|error| CommandError::FAILURE(error.to_string()));
+}
+
+fn print_statistics() {
+    let statistics = game_tree::enumerate(board::new(), CellType::PLAYER_1);
+
+    println!("Games:            {:>7}", statistics.games);
+    println!("X wins:           {:>7}", statistics.player_1_wins);
+    println!("O wins:           {:>7}", statistics.player_2_wins);
+    println!("Draws:            {:>7}", statistics.draws);
+    println!("Positions:        {:>7}", statistics.positions());
+    println!("Unique positions: {:>7}", statistics.unique_positions);
+    println!("Positions by number of moves:");
+    for (depth, positions) in statistics.positions_per_depth.iter().enumerate() {
+        println!("  {}: {:>5}", depth, positions);
+    }
+}
+
+/// Options shared by the `arena` and `tournament` commands.
+struct MatchOptions {
+    bots: Vec<String>,
+    games: usize,
+    openings: Openings,
+    file: String,
+}
+
+fn parse_match_options(args: Vec<String>) -> Result<MatchOptions, String> {
+    let mut bots: Vec<String> = vec![];
+    let mut games = 100;
+    let mut plies = 0;
+    let mut seed = 0;
+    let mut file = String::from("leaderboard.tsv");
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--games" => games = parse_number(&value()?)?,
+            "--plies" => plies = parse_number(&value()?)?,
+            "--seed" => seed = parse_number(&value()?)? as u64,
+            "--file" => file = value()?,
+            _ if !arg.starts_with("--") => bots.push(arg.to_lowercase()),
+            _ => return Err(format!("unknown argument `{}`", arg)),
+        }
+    }
+
+    let openings = match plies {
+        0 => Openings::EMPTY,
+        _ => Openings::RANDOM { plies, seed },
+    };
+    return Ok(MatchOptions {
+        bots,
+        games,
+        openings,
+        file,
+    });
+}
+
+fn run_arena(args: Vec<String>) -> Result<(), CommandError> {
+    let options = parse_match_options(args)?;
+    let (first, second) = match &options.bots[..] {
+        [first, second] => (parse_bot(first)?, parse_bot(second)?),
+        _ => {
+            return Err(CommandError::USAGE(String::from(
+                "arena needs exactly two bots",
+            )))
+        }
+    };
+
+    let result = arena::play_match(
+        first.as_ref(),
+        second.as_ref(),
+        options.games,
+        &options.openings,
+    );
+    println!(
+        "{} against {}, {} games:\n",
+        options.bots[0], options.bots[1], options.games
+    );
+    print!("{}", result);
+    return Ok(());
+}
+
+fn run_tournament(args: Vec<String>) -> Result<(), CommandError> {
+    let mut options = parse_match_options(args)?;
+    if options.bots.is_empty() {
+        options.bots = BOTS.iter().map(|name| name.to_string()).collect();
+    }
+    let analyzers = options
+        .bots
+        .iter()
+        .map(|name| parse_bot(name))
+        .collect::<Result<Vec<_>, _>>()?;
+    let bots: Vec<(&str, &dyn Analyzer)> = options
+        .bots
+        .iter()
+        .zip(&analyzers)
+        .map(|(name, analyzer)| (name.as_str(), analyzer.as_ref()))
+        .collect();
+
+    let path = Path::new(&options.file);
+    let mut leaderboard =
+        Leaderboard::load(path).map_err(|error| CommandError::FAILURE(error.to_string()))?;
+    tournament::run_round_robin(&bots, options.games, &options.openings, &mut leaderboard)
+        .map_err(|error| CommandError::USAGE(error.to_string()))?;
+    leaderboard
+        .save(path)
+        .map_err(|error| CommandError::FAILURE(error.to_string()))?;
+
+    print!("{}", leaderboard);
+    return Ok(());
+}
+
+const BOTS: [&str; 7] = [
+    "random",
+    "easy",
+    "medium",
+    "perfect",
+    "heuristic",
+    "book",
+    "mcts",
+];
+
+fn parse_bot(value: &str) -> Result<Box<dyn Analyzer>, String> {
+    match value.to_lowercase().as_str() {
+        "heuristic" => Ok(Box::new(heuristic::new())),
+        "book" => Ok(Box::new(book::new())),
+        "mcts" => Ok(Box::new(mcts::new(MctsConfig::default()))),
+        name => parse_difficulty(name)
+            .map(|difficulty| Box::new(ANL::new(difficulty)) as Box<dyn Analyzer>)
+            .map_err(|_| format!("unknown bot `{}`", value)),
+    }
+}
+
+fn parse_number(value: &str) -> Result<usize, String> {
+    return value
+        .parse()
+        .map_err(|_| format!("`{}` is not a number", value));
+}
+
+fn parse_options(args: Vec<String>) -> Result<Options, String> {
+    let mut mode = String::from("human-bot");
+    let mut human = CellType::PLAYER_1;
+    let mut first = CellType::PLAYER_1;
+    let mut difficulty = Difficulty::PERFECT;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--mode" => mode = value()?,
+            "--human" => human = parse_side(&value()?)?,
+            "--first" => first = parse_side(&value()?)?,
+            "--difficulty" => difficulty = parse_difficulty(&value()?)?,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            _ => return Err(format!("unknown argument `{}`", arg)),
+        }
+    }
+
+    let (player_1, player_2) = match (mode.as_str(), human) {
+        ("human-bot", CellType::PLAYER_1) => (PlayerKind::HUMAN, PlayerKind::BOT),
+        ("human-bot", _) => (PlayerKind::BOT, PlayerKind::HUMAN),
+        ("bot-bot", _) => (PlayerKind::BOT, PlayerKind::BOT),
+        ("human-human", _) => (PlayerKind::HUMAN, PlayerKind::HUMAN),
+        _ => return Err(format!("unknown mode `{}`", mode)),
+    };
+
+    return Ok(Options {
+        player_1,
+        player_2,
+        first,
+        difficulty,
+    });
+}
+
+fn parse_side(value: &str) -> Result<CellType, String> {
+    match value.to_lowercase().as_str() {
+        "x" => Ok(CellType::PLAYER_1),
+        "o" => Ok(CellType::PLAYER_2),
+        _ => Err(format!("unknown side `{}`, expected x or o", value)),
+    }
+}
+
+fn parse_difficulty(value: &str) -> Result<Difficulty, String> {
+    match value.to_lowercase().as_str() {
+        "random" => Ok(Difficulty::RANDOM),
+        "easy" => Ok(Difficulty::EASY),
+        "medium" => Ok(Difficulty::MEDIUM),
+        "perfect" => Ok(Difficulty::PERFECT),
+        _ => Err(format!("unknown difficulty `{}`", value)),
+    }
+}
+
+impl Options {
+    fn player_kind(&self, cell_type: CellType) -> PlayerKind {
+        match cell_type {
+            CellType::PLAYER_1 => return self.player_1,
+            _ => return self.player_2,
+        }
+    }
+
+    fn has_human(&self) -> bool {
+        return self.player_1 == PlayerKind::HUMAN || self.player_2 == PlayerKind::HUMAN;
+    }
+}
+
+fn play(options: &Options, input: &mut impl BufRead, output: &mut impl Write) -> io::Result<()> {
+    let game_analyzer = ANL::new(options.difficulty);
+    let mut game_state = GameState::new(options.first);
+
+    show(&game_state, output)?;
+
+    loop {
+        let to_move = game_state.side_to_move();
+        let is_playing = game_state.is_game_over() == GameOverType::PLAYING;
+
+        if is_playing && options.player_kind(to_move) == PlayerKind::BOT {
+            let coord = game_analyzer
+                .get_best_move(Box::new(game_state.clone()), to_move)
+                .expect("analyzer has a move while the game is playing");
+            writeln!(output, "{} plays {} {}", to_move, coord.x, coord.y)?;
+            game_state
+                .play(coord)
+                .expect("analyzer only returns legal moves");
+            show(&game_state, output)?;
+            continue;
+        }
+
+        // Once the game is over, the humans can still take moves back or
+        // start again.
+        if !is_playing && !options.has_human() {
+            return Ok(());
+        }
+
+        match read_command(&game_state, input, output)? {
+            Command::MOVE(coord) => {
+                game_state
+                    .play(coord)
+                    .expect("move was checked before it was played");
+            }
+            Command::UNDO => {
+                if !undo_turn(&mut game_state, options) {
+                    writeln!(output, "Nothing to undo.")?;
+                    continue;
+                }
+            }
+            Command::REDO => {
+                if !redo_turn(&mut game_state, options) {
+                    writeln!(output, "Nothing to redo.")?;
+                    continue;
+                }
+            }
+            Command::RESTART => game_state = GameState::new(options.first),
+            Command::QUIT => return Ok(()),
+        }
+        show(&game_state, output)?;
+    }
+}
+
+/// Takes back moves up to and including the last one a human played, so that
+/// the bot's reply goes with it. Returns false when no human has moved yet.
+fn undo_turn(game_state: &mut GameState, options: &Options) -> bool {
+    let has_human_move = game_state
+        .history()
+        .iter()
+        .any(|(_, cell_type)| options.player_kind(*cell_type) == PlayerKind::HUMAN);
+    if !has_human_move {
+        return false;
+    }
+
+    while let Some((_, cell_type)) = game_state.undo() {
+        if options.player_kind(cell_type) == PlayerKind::HUMAN {
+            break;
+        }
+    }
+    return true;
+}
+
+/// Plays an undone human move again together with the bot replies that
+/// followed it. Returns false when there is nothing to redo.
+fn redo_turn(game_state: &mut GameState, options: &Options) -> bool {
+    if game_state.redo().is_none() {
+        return false;
+    }
+
+    while options.player_kind(game_state.side_to_move()) == PlayerKind::BOT {
+        if game_state.redo().is_none() {
+            break;
+        }
+    }
+    return true;
+}
+
+/// Prints the board, and how the game ended once it is over.
+fn show(game_state: &GameState, output: &mut impl Write) -> io::Result<()> {
+    writeln!(output, "{}", render(&game_state.get_board_state()))?;
+
+    let outcome = game_state.get_outcome();
+    let winner = match outcome.result {
+        GameOverType::PLAYING => return Ok(()),
+        GameOverType::PLAYER_1_WIN => "X",
+        GameOverType::PLAYER_2_WIN => "O",
+        GameOverType::DRAW => {
+            writeln!(output, "It's a draw.")?;
+            return Ok(());
+        }
+    };
+    let cells: Vec<String> = outcome
+        .winning_cells()
+        .iter()
+        .map(|coord| format!("{} {}", coord.x, coord.y))
+        .collect();
+    writeln!(output, "{} wins on {}!", winner, cells.join(", "))?;
+
+    return Ok(());
+}
+
+/// Prompts until a command or a legal move is entered. The end of the input
+/// counts as `quit`.
+fn read_command(
+    game_state: &GameState,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<Command> {
+    loop {
+        if game_state.is_game_over() == GameOverType::PLAYING {
+            write!(output, "{} to move> ", game_state.side_to_move())?;
+        } else {
+            write!(output, "undo, restart or quit> ")?;
+        }
+        output.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(Command::QUIT);
+        }
+
+        match line.trim() {
+            "quit" => return Ok(Command::QUIT),
+            "undo" => return Ok(Command::UNDO),
+            "redo" => return Ok(Command::REDO),
+            "restart" => return Ok(Command::RESTART),
+            _ => (),
+        }
+
+        let coord = match parse_coord(&line) {
+            Some(coord) => coord,
+            None => {
+                writeln!(
+                    output,
+                    "Enter a move as `<x> <y>`, or undo, redo, restart or quit."
+                )?;
+                continue;
+            }
+        };
+
+        if let Err(error) = game_state.try_set_player(coord, game_state.side_to_move()) {
+            writeln!(output, "Illegal move: {}.", error)?;
+            continue;
+        }
+
+        return Ok(Command::MOVE(coord));
+    }
+}
+
+fn parse_coord(line: &str) -> Option<Coord> {
+    let values: Vec<isize> = line
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse::<isize>())
+        .collect::<Result<_, _>>()
+        .ok()?;
+
+    match values[..] {
+        [x, y] => Some(Coord { x, y }),
+        _ => None,
+    }
+}
+
+fn render(board_state: &board::Board) -> String {
+    let header: Vec<String> = (0..board_state.width)
+        .map(|x| format!("{:^3}", x))
+        .collect();
+    let rows: Vec<String> = board_state
+        .chunks(board_state.width)
+        .enumerate()
+        .map(|(y, row)| {
+            let cells: Vec<&str> = row.iter().map(|cell| cell_symbol(*cell)).collect();
+            format!("{}  {}", y, cells.join(" | "))
+        })
+        .collect();
+    let separator = format!("\n  {}\n", vec!["---"; board_state.width].join("+"));
+
+    let header = format!("  {}", header.join(" "));
+    return format!("{}\n{}\n", header.trim_end(), rows.join(&separator));
+}
+
+fn cell_symbol(cell_type: CellType) -> &'static str {
+    match cell_type {
+        CellType::NON => " ",
+        CellType::PLAYER_1 => "X",
+        CellType::PLAYER_2 => "O",
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod bitboard_tests {
+    use crate::board::bitboard::{self, BitBoard};
+    use crate::board::{self, Board, CellType, Coord, Game, GameOverType, MoveError};
+    use std::collections::HashSet;
+
+    fn assert_same_as_vector_board(
+        vector_board: Box<dyn Game>,
+        bit_board: Box<dyn Game>,
+        to_move: CellType,
+        visited: &mut HashSet<BitBoard>,
+    ) {
+        let board_state = bit_board.get_board_state();
+        if !visited.insert(BitBoard::from_board(&board_state).unwrap()) {
+            return;
+        }
+
+        assert_eq!(vector_board.get_board_state(), board_state);
+        assert_eq!(vector_board.is_game_over(), bit_board.is_game_over());
+
+        if vector_board.is_game_over() != GameOverType::PLAYING {
+            return;
+        }
+
+        for position in 0..9 {
+            let coord = Coord {
+                x: position % 3,
+                y: position / 3,
+            };
+            let vector_result = vector_board.try_set_player(coord, to_move);
+            let bit_result = bit_board.try_set_player(coord, to_move);
+
+            match (vector_result, bit_result) {
+                (Ok(vector_board), Ok(bit_board)) => assert_same_as_vector_board(
+                    vector_board,
+                    bit_board,
+                    to_move.opponent(),
+                    visited,
+                ),
+                (vector_result, bit_result) => {
+                    assert_eq!(vector_result.err(), bit_result.err())
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_match_vector_board_on_every_reachable_position() {
+        let mut visited = HashSet::new();
+
+        assert_same_as_vector_board(
+            board::new(),
+            bitboard::new(),
+            CellType::PLAYER_1,
+            &mut visited,
+        );
+        assert_eq!(visited.len(), 5478);
+    }
+
+    #[test]
+    fn should_convert_from_and_to_board() {
+        let board_state = board::new()
+            .set_player(Coord { x: 0, y: 0 }, CellType::PLAYER_1)
+            .set_player(Coord { x: 2, y: 1 }, CellType::PLAYER_2)
+            .get_board_state();
+
+        let bitboard = BitBoard::from_board(&board_state).unwrap();
+
+        assert_eq!(bitboard.player_1, 0b000_000_001);
+        assert_eq!(bitboard.player_2, 0b000_100_000);
+        assert_eq!(bitboard.get_board_state(), board_state);
+        assert_eq!(BitBoard::from_board(&Board::new(4, 4, 3)), None);
+    }
+
+    #[test]
+    fn should_return_errors_for_illegal_moves() {
+        let game_board = bitboard::new().set_player(Coord { x: 1, y: 1 }, CellType::PLAYER_1);
+
+        assert_eq!(
+            game_board
+                .try_set_player(Coord { x: 1, y: 1 }, CellType::PLAYER_2)
+                .err(),
+            Some(MoveError::OCCUPIED_CELL)
+        );
+        assert_eq!(
+            game_board
+                .try_set_player(Coord { x: 3, y: 0 }, CellType::PLAYER_2)
+                .err(),
+            Some(MoveError::OUT_OF_BOUNDS)
+        );
+        assert_eq!(
+            game_board
+                .try_set_player(Coord { x: 0, y: 0 }, CellType::PLAYER_1)
+                .err(),
+            Some(MoveError::WRONG_PLAYER)
+        );
+    }
+}
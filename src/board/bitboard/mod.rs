@@ -0,0 +1,161 @@
+mod bitboard_tests;
+
+use crate::board::{Board, CellType, Coord, Game, GameOverType, MoveError};
+
+/// Bit masks of the eight winning lines, bit `n` standing for position `n`.
+const WIN_MASKS: [u16; 8] = [
+    0b000_000_111,
+    0b000_111_000,
+    0b111_000_000,
+    0b001_001_001,
+    0b010_010_010,
+    0b100_100_100,
+    0b100_010_001,
+    0b001_010_100,
+];
+
+const FULL_MASK: u16 = 0b111_111_111;
+
+/// A 3x3 board stored as one bit mask per player. It is `Copy`, and win
+/// checks are a handful of mask tests, which makes it the cheaper backend
+/// for deep searches and self-play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BitBoard {
+    pub player_1: u16,
+    pub player_2: u16,
+}
+
+impl BitBoard {
+    pub fn new() -> BitBoard {
+        return BitBoard::default();
+    }
+
+    /// Converts a 3x3, three in a row `board`. Returns `None` for any other
+    /// size.
+    pub fn from_board(board: &Board) -> Option<BitBoard> {
+        if board.width != 3 || board.height != 3 || board.win_length != 3 {
+            return None;
+        }
+
+        let mut bitboard = BitBoard::new();
+        for (position, cell) in board.iter().enumerate() {
+            match cell {
+                CellType::PLAYER_1 => bitboard.player_1 |= 1 << position,
+                CellType::PLAYER_2 => bitboard.player_2 |= 1 << position,
+                CellType::NON => (),
+            }
+        }
+        return Some(bitboard);
+    }
+
+    pub fn mask_of(&self, cell_type: CellType) -> u16 {
+        match cell_type {
+            CellType::PLAYER_1 => self.player_1,
+            CellType::PLAYER_2 => self.player_2,
+            CellType::NON => !(self.player_1 | self.player_2) & FULL_MASK,
+        }
+    }
+
+    pub fn has_won(&self, cell_type: CellType) -> bool {
+        if cell_type == CellType::NON {
+            return false;
+        }
+
+        let mask = self.mask_of(cell_type);
+        for win_mask in WIN_MASKS {
+            if mask & win_mask == win_mask {
+                return true;
+            }
+        }
+        return false;
+    }
+
+    fn check_move(&self, coord: &Coord, cell_type: CellType) -> Result<u16, MoveError> {
+        if self.is_game_over() != GameOverType::PLAYING {
+            return Err(MoveError::GAME_OVER);
+        }
+
+        let count_of = |cell_type| self.mask_of(cell_type).count_ones();
+        if cell_type == CellType::NON || count_of(cell_type) > count_of(cell_type.opponent()) {
+            return Err(MoveError::WRONG_PLAYER);
+        }
+
+        let bit = match bit_of(coord) {
+            Some(bit) => bit,
+            None => return Err(MoveError::OUT_OF_BOUNDS),
+        };
+
+        if (self.player_1 | self.player_2) & bit != 0 {
+            return Err(MoveError::OCCUPIED_CELL);
+        }
+
+        return Ok(bit);
+    }
+
+    fn with_bit(&self, bit: u16, cell_type: CellType) -> BitBoard {
+        let mut bitboard = *self;
+        match cell_type {
+            CellType::PLAYER_1 => bitboard.player_1 |= bit,
+            CellType::PLAYER_2 => bitboard.player_2 |= bit,
+            CellType::NON => (),
+        }
+        return bitboard;
+    }
+}
+
+impl Game for BitBoard {
+    fn get_board_state(&self) -> Board {
+        let mut board = Board::new(3, 3, 3);
+        for position in 0..9 {
+            if self.player_1 & (1 << position) != 0 {
+                board[position] = CellType::PLAYER_1;
+            } else if self.player_2 & (1 << position) != 0 {
+                board[position] = CellType::PLAYER_2;
+            }
+        }
+        return board;
+    }
+
+    fn is_game_over(&self) -> GameOverType {
+        if self.has_won(CellType::PLAYER_1) {
+            return GameOverType::PLAYER_1_WIN;
+        }
+        if self.has_won(CellType::PLAYER_2) {
+            return GameOverType::PLAYER_2_WIN;
+        }
+        if self.player_1 | self.player_2 == FULL_MASK {
+            return GameOverType::DRAW;
+        }
+        return GameOverType::PLAYING;
+    }
+
+    fn set_player(&self, coord: Coord, cell_type: CellType) -> Box<dyn Game> {
+        match bit_of(&coord) {
+            Some(bit) if (self.player_1 | self.player_2) & bit == 0 => {
+                return Box::new(self.with_bit(bit, cell_type));
+            }
+            _ => return Box::new(*self),
+        }
+    }
+
+    fn try_set_player(
+        &self,
+        coord: Coord,
+        cell_type: CellType,
+    ) -> Result<Box<dyn Game>, MoveError> {
+        let bit = self.check_move(&coord, cell_type)?;
+        return Ok(Box::new(self.with_bit(bit, cell_type)));
+    }
+}
+
+/// Creates an empty 3x3 board backed by bit masks.
+pub fn new() -> Box<dyn Game> {
+    return Box::new(BitBoard::new());
+}
+
+fn bit_of(coord: &Coord) -> Option<u16> {
+    if !(0..3).contains(&coord.x) || !(0..3).contains(&coord.y) {
+        return None;
+    }
+    return Some(1 << (coord.y * 3 + coord.x));
+}
@@ -40,15 +40,94 @@ mod board_test {
 
     #[test]
     fn should_perform_valid_coord_to_position_translation() {
-        assert_eq!(coord_to_position(&Coord { x: 0, y: 0 }), 0);
-        assert_eq!(coord_to_position(&Coord { x: 1, y: 0 }), 1);
-        assert_eq!(coord_to_position(&Coord { x: 2, y: 0 }), 2);
-        assert_eq!(coord_to_position(&Coord { x: 0, y: 1 }), 3);
-        assert_eq!(coord_to_position(&Coord { x: 1, y: 1 }), 4);
-        assert_eq!(coord_to_position(&Coord { x: 2, y: 1 }), 5);
-        assert_eq!(coord_to_position(&Coord { x: 0, y: 2 }), 6);
-        assert_eq!(coord_to_position(&Coord { x: 1, y: 2 }), 7);
-        assert_eq!(coord_to_position(&Coord { x: 2, y: 2 }), 8);
+        assert_eq!(coord_to_position(&Coord { x: 0, y: 0 }, 3), 0);
+        assert_eq!(coord_to_position(&Coord { x: 1, y: 0 }, 3), 1);
+        assert_eq!(coord_to_position(&Coord { x: 2, y: 0 }, 3), 2);
+        assert_eq!(coord_to_position(&Coord { x: 0, y: 1 }, 3), 3);
+        assert_eq!(coord_to_position(&Coord { x: 1, y: 1 }, 3), 4);
+        assert_eq!(coord_to_position(&Coord { x: 2, y: 1 }, 3), 5);
+        assert_eq!(coord_to_position(&Coord { x: 0, y: 2 }, 3), 6);
+        assert_eq!(coord_to_position(&Coord { x: 1, y: 2 }, 3), 7);
+        assert_eq!(coord_to_position(&Coord { x: 2, y: 2 }, 3), 8);
+    }
+
+    #[test]
+    fn should_translate_coords_using_board_width() {
+        let board = Board::new(4, 2, 2);
+
+        assert_eq!(board.position_of(&Coord { x: 3, y: 0 }), Some(3));
+        assert_eq!(board.position_of(&Coord { x: 0, y: 1 }), Some(4));
+        assert_eq!(board.position_of(&Coord { x: 3, y: 1 }), Some(7));
+        assert_eq!(board.position_of(&Coord { x: 0, y: 2 }), None);
+        assert_eq!(board.position_of(&Coord { x: 4, y: 0 }), None);
+        assert_eq!(board.coord_of(6), Coord { x: 2, y: 1 });
+    }
+
+    #[test]
+    fn should_generate_every_winning_line() {
+        assert_eq!(Board::new(3, 3, 3).lines().count(), 8);
+        assert_eq!(Board::new(4, 4, 3).lines().count(), 24);
+        assert_eq!(Board::new(5, 3, 4).lines().count(), 6);
+        assert_eq!(Board::new(15, 15, 5).lines().count(), 572);
+
+        let lines: Vec<Vec<usize>> = Board::new(3, 3, 3)
+            .lines()
+            .map(|line| line.positions().collect())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                vec![0, 1, 2],
+                vec![3, 4, 5],
+                vec![6, 7, 8],
+                vec![0, 3, 6],
+                vec![1, 4, 7],
+                vec![2, 5, 8],
+                vec![0, 4, 8],
+                vec![2, 4, 6],
+            ]
+        );
+    }
+
+    #[test]
+    fn should_return_win_on_four_by_four_connect_three() {
+        let mut game_board = new_with_size(4, 4, 3);
+
+        game_board = game_board.set_player(Coord { x: 3, y: 1 }, CellType::PLAYER_2);
+        game_board = game_board.set_player(Coord { x: 2, y: 2 }, CellType::PLAYER_2);
+
+        assert_eq!(game_board.is_game_over(), GameOverType::PLAYING);
+
+        game_board = game_board.set_player(Coord { x: 1, y: 3 }, CellType::PLAYER_2);
+
+        assert_eq!(game_board.is_game_over(), GameOverType::PLAYER_2_WIN);
+    }
+
+    #[test]
+    fn should_return_win_on_gomoku_board() {
+        let mut game_board = new_with_size(15, 15, 5);
+
+        for y in 10..14 {
+            game_board = game_board.set_player(Coord { x: 14, y }, CellType::PLAYER_1);
+        }
+
+        assert_eq!(game_board.get_board_state().len(), 225);
+        assert_eq!(game_board.is_game_over(), GameOverType::PLAYING);
+
+        game_board = game_board.set_player(Coord { x: 14, y: 14 }, CellType::PLAYER_1);
+
+        assert_eq!(game_board.is_game_over(), GameOverType::PLAYER_1_WIN);
+    }
+
+    #[test]
+    fn should_return_out_of_bounds_error_on_rectangular_board() {
+        let game_board = new_with_size(4, 2, 2);
+
+        let result = game_board.try_set_player(Coord { x: 3, y: 2 }, CellType::PLAYER_1);
+        assert_eq!(result.err(), Some(MoveError::OUT_OF_BOUNDS));
+
+        let result = game_board.try_set_player(Coord { x: 3, y: 1 }, CellType::PLAYER_1);
+        assert!(result.is_ok());
     }
 
     #[test]
@@ -97,4 +176,78 @@ mod board_test {
 
         assert_eq!(victor, GameOverType::DRAW);
     }
+
+    #[test]
+    fn should_not_panic_when_setting_player_out_of_bounds() {
+        let game_board = new();
+
+        let game_state = game_board
+            .set_player(Coord { x: -1, y: 0 }, CellType::PLAYER_1)
+            .set_player(Coord { x: 3, y: 3 }, CellType::PLAYER_1)
+            .get_board_state();
+
+        assert_eq!(game_state, new().get_board_state());
+    }
+
+    #[test]
+    fn should_place_player_when_move_is_legal() {
+        let game_board = new();
+
+        let game_state = game_board
+            .try_set_player(Coord { x: 2, y: 1 }, CellType::PLAYER_2)
+            .unwrap()
+            .get_board_state();
+
+        assert_eq!(game_state[5], CellType::PLAYER_2);
+    }
+
+    #[test]
+    fn should_return_occupied_cell_error() {
+        let game_board = new().set_player(Coord { x: 1, y: 1 }, CellType::PLAYER_1);
+
+        let result = game_board.try_set_player(Coord { x: 1, y: 1 }, CellType::PLAYER_2);
+
+        assert_eq!(result.err(), Some(MoveError::OCCUPIED_CELL));
+    }
+
+    #[test]
+    fn should_return_out_of_bounds_error() {
+        let game_board = new();
+
+        for coord in [
+            Coord { x: -1, y: 0 },
+            Coord { x: 0, y: -1 },
+            Coord { x: 3, y: 0 },
+            Coord { x: 0, y: 3 },
+        ] {
+            let result = game_board.try_set_player(coord, CellType::PLAYER_1);
+
+            assert_eq!(result.err(), Some(MoveError::OUT_OF_BOUNDS));
+        }
+    }
+
+    #[test]
+    fn should_return_wrong_player_error() {
+        let game_board = new().set_player(Coord { x: 0, y: 0 }, CellType::PLAYER_1);
+
+        let result = game_board.try_set_player(Coord { x: 1, y: 0 }, CellType::PLAYER_1);
+        assert_eq!(result.err(), Some(MoveError::WRONG_PLAYER));
+
+        let result = game_board.try_set_player(Coord { x: 1, y: 0 }, CellType::NON);
+        assert_eq!(result.err(), Some(MoveError::WRONG_PLAYER));
+    }
+
+    #[test]
+    fn should_return_game_over_error() {
+        let game_board = new()
+            .set_player(Coord { x: 0, y: 0 }, CellType::PLAYER_1)
+            .set_player(Coord { x: 0, y: 1 }, CellType::PLAYER_2)
+            .set_player(Coord { x: 1, y: 0 }, CellType::PLAYER_1)
+            .set_player(Coord { x: 1, y: 1 }, CellType::PLAYER_2)
+            .set_player(Coord { x: 2, y: 0 }, CellType::PLAYER_1);
+
+        let result = game_board.try_set_player(Coord { x: 2, y: 1 }, CellType::PLAYER_2);
+
+        assert_eq!(result.err(), Some(MoveError::GAME_OVER));
+    }
 }
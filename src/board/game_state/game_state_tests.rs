@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod game_state_tests {
+    use crate::board::{Board, CellType, Coord, Game, GameOverType, GameState, MoveError};
+
+    #[test]
+    fn should_start_with_configured_player_to_move() {
+        assert_eq!(
+            GameState::new(CellType::PLAYER_1).side_to_move(),
+            CellType::PLAYER_1
+        );
+        assert_eq!(
+            GameState::new(CellType::PLAYER_2).side_to_move(),
+            CellType::PLAYER_2
+        );
+    }
+
+    #[test]
+    fn should_alternate_turns_and_record_history() {
+        let mut game_state = GameState::new(CellType::PLAYER_2);
+
+        game_state.play(Coord { x: 1, y: 1 }).unwrap();
+        game_state.play(Coord { x: 0, y: 0 }).unwrap();
+
+        assert_eq!(game_state.side_to_move(), CellType::PLAYER_2);
+        assert_eq!(
+            game_state.history(),
+            &[
+                (Coord { x: 1, y: 1 }, CellType::PLAYER_2),
+                (Coord { x: 0, y: 0 }, CellType::PLAYER_1),
+            ]
+        );
+        assert_eq!(game_state.get_board_state()[4], CellType::PLAYER_2);
+        assert_eq!(game_state.get_board_state()[0], CellType::PLAYER_1);
+    }
+
+    #[test]
+    fn should_reject_move_from_player_not_to_move() {
+        let game_state = GameState::new(CellType::PLAYER_1);
+
+        let result = game_state.try_set_player(Coord { x: 0, y: 0 }, CellType::PLAYER_2);
+
+        assert_eq!(result.err(), Some(MoveError::WRONG_PLAYER));
+    }
+
+    #[test]
+    fn should_ignore_illegal_move_in_set_player() {
+        let game_state = GameState::new(CellType::PLAYER_1);
+
+        let game_board = game_state
+            .set_player(Coord { x: 0, y: 0 }, CellType::PLAYER_1)
+            .set_player(Coord { x: 0, y: 0 }, CellType::PLAYER_2)
+            .set_player(Coord { x: 1, y: 0 }, CellType::PLAYER_1);
+
+        let game_board_state = game_board.get_board_state();
+        assert_eq!(game_board_state[0], CellType::PLAYER_1);
+        assert_eq!(game_board_state[1], CellType::NON);
+    }
+
+    #[test]
+    fn should_reject_move_after_game_over() {
+        let mut game_state = GameState::new(CellType::PLAYER_1);
+        for (x, y) in [(0, 0), (0, 1), (1, 0), (1, 1), (2, 0)] {
+            game_state.play(Coord { x, y }).unwrap();
+        }
+
+        assert_eq!(game_state.is_game_over(), GameOverType::PLAYER_1_WIN);
+        assert_eq!(
+            game_state.play(Coord { x: 2, y: 1 }),
+            Err(MoveError::GAME_OVER)
+        );
+    }
+
+    #[test]
+    fn should_undo_last_move() {
+        let mut game_state = GameState::new(CellType::PLAYER_1);
+        game_state.play(Coord { x: 2, y: 2 }).unwrap();
+        game_state.play(Coord { x: 0, y: 2 }).unwrap();
+
+        let undone_move = game_state.undo();
+
+        assert_eq!(
+            undone_move,
+            Some((Coord { x: 0, y: 2 }, CellType::PLAYER_2))
+        );
+        assert_eq!(game_state.side_to_move(), CellType::PLAYER_2);
+        assert_eq!(game_state.history().len(), 1);
+        assert_eq!(game_state.get_board_state()[6], CellType::NON);
+
+        game_state.undo();
+
+        assert_eq!(game_state.undo(), None);
+        assert_eq!(game_state, GameState::new(CellType::PLAYER_1));
+    }
+
+    #[test]
+    fn should_redo_undone_moves_in_order() {
+        let mut game_state = GameState::new(CellType::PLAYER_1);
+        game_state.play(Coord { x: 2, y: 2 }).unwrap();
+        game_state.play(Coord { x: 0, y: 2 }).unwrap();
+        let played = game_state.clone();
+        game_state.undo();
+        game_state.undo();
+
+        assert_eq!(
+            game_state.redo(),
+            Some((Coord { x: 2, y: 2 }, CellType::PLAYER_1))
+        );
+        assert_eq!(
+            game_state.redo(),
+            Some((Coord { x: 0, y: 2 }, CellType::PLAYER_2))
+        );
+        assert_eq!(game_state.redo(), None);
+        assert_eq!(game_state, played);
+    }
+
+    #[test]
+    fn should_forget_undone_moves_after_new_move() {
+        let mut game_state = GameState::new(CellType::PLAYER_1);
+        game_state.play(Coord { x: 2, y: 2 }).unwrap();
+        game_state.undo();
+
+        game_state.play(Coord { x: 1, y: 1 }).unwrap();
+
+        assert_eq!(game_state.redo(), None);
+        assert_eq!(
+            game_state.history(),
+            &[(Coord { x: 1, y: 1 }, CellType::PLAYER_1)]
+        );
+    }
+
+    #[test]
+    fn should_record_and_replay_game() {
+        let start: Board = "X../.O./...".parse().unwrap();
+        let mut game_state = GameState::from_board(start.clone(), CellType::PLAYER_1);
+        for (x, y) in [(2, 0), (1, 0), (1, 2), (0, 2)] {
+            game_state.play(Coord { x, y }).unwrap();
+        }
+
+        let record = game_state.record();
+
+        assert_eq!(record.start, start);
+        assert_eq!(record.first_player, CellType::PLAYER_1);
+        assert_eq!(record.moves, game_state.history());
+        assert_eq!(record.result, GameOverType::PLAYING);
+        assert_eq!(record.replay(), Ok(game_state));
+    }
+
+    #[test]
+    fn should_refuse_replay_of_out_of_turn_record() {
+        let mut record = GameState::new(CellType::PLAYER_1).record();
+        record
+            .moves
+            .push((Coord { x: 0, y: 0 }, CellType::PLAYER_2));
+
+        assert_eq!(record.replay(), Err(MoveError::WRONG_PLAYER));
+    }
+}
@@ -0,0 +1,182 @@
+mod game_state_tests;
+
+use crate::board::{self, Board, CellType, Coord, Game, GameOverType, MoveError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A game in progress: the board together with the moves that led to it.
+/// Unlike a bare `Board`, it knows whose turn it is and only accepts moves
+/// from that player.
+#[derive(Debug, Clone)]
+pub struct GameState {
+    board: Board,
+    first_player: CellType,
+    history: Vec<(Coord, CellType)>,
+    /// Undone moves that `redo` can play again, the next one last.
+    undone: Vec<(Coord, CellType)>,
+}
+
+/// Two games are equal when they reached the same position by the same
+/// moves, whatever they could still redo.
+impl PartialEq for GameState {
+    fn eq(&self, other: &GameState) -> bool {
+        return self.board == other.board
+            && self.first_player == other.first_player
+            && self.history == other.history;
+    }
+}
+
+/// Everything needed to replay a game: the position it started from, the
+/// moves in order and how it ended.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameRecord {
+    pub start: Board,
+    pub first_player: CellType,
+    pub moves: Vec<(Coord, CellType)>,
+    /// How the game ended. After a forfeit, this is a win for the other
+    /// player while the replayed board may still be playing.
+    pub result: GameOverType,
+    /// Whether the loser gave up the game by failing to return a legal move,
+    /// rather than the game ending on the board.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub forfeit: bool,
+}
+
+impl GameRecord {
+    /// Plays the recorded moves again from the start position.
+    pub fn replay(&self) -> Result<GameState, MoveError> {
+        let mut game_state = GameState::from_board(self.start.clone(), self.first_player);
+        for (coord, cell_type) in &self.moves {
+            if *cell_type != game_state.side_to_move() {
+                return Err(MoveError::WRONG_PLAYER);
+            }
+            game_state.play(*coord)?;
+        }
+        return Ok(game_state);
+    }
+}
+
+impl GameState {
+    /// Starts a game on an empty board with `first_player` to move.
+    pub fn new(first_player: CellType) -> GameState {
+        return GameState::from_board(board::new().get_board_state(), first_player);
+    }
+
+    /// Continues a game from `board` with `side_to_move` to move. The moves
+    /// that led to `board` are unknown, so they cannot be undone.
+    pub fn from_board(board: Board, side_to_move: CellType) -> GameState {
+        return GameState {
+            board,
+            first_player: side_to_move,
+            history: vec![],
+            undone: vec![],
+        };
+    }
+
+    pub fn side_to_move(&self) -> CellType {
+        match self.history.last() {
+            Some((_, cell_type)) => cell_type.opponent(),
+            None => self.first_player,
+        }
+    }
+
+    /// Moves played so far, oldest first.
+    pub fn history(&self) -> &[(Coord, CellType)] {
+        return &self.history;
+    }
+
+    pub fn record(&self) -> GameRecord {
+        let mut start = self.board.clone();
+        for (coord, _) in &self.history {
+            let position = board::coord_to_position(coord, start.width);
+            start[position] = CellType::NON;
+        }
+
+        return GameRecord {
+            start,
+            first_player: self.first_player,
+            moves: self.history.clone(),
+            result: self.board.is_game_over(),
+            forfeit: false,
+        };
+    }
+
+    /// Places the side to move on `coord`. Moves that were undone can no
+    /// longer be redone afterwards.
+    pub fn play(&mut self, coord: Coord) -> Result<(), MoveError> {
+        self.place(coord)?;
+        self.undone.clear();
+        return Ok(());
+    }
+
+    /// Takes back the last move and returns it, or `None` when there is
+    /// nothing left to undo.
+    pub fn undo(&mut self) -> Option<(Coord, CellType)> {
+        let (coord, cell_type) = self.history.pop()?;
+        let position = board::coord_to_position(&coord, self.board.width);
+        self.board[position] = CellType::NON;
+        self.undone.push((coord, cell_type));
+        return Some((coord, cell_type));
+    }
+
+    /// Plays the last undone move again and returns it, or `None` when no
+    /// move was undone since the last one played.
+    pub fn redo(&mut self) -> Option<(Coord, CellType)> {
+        let (coord, cell_type) = *self.undone.last()?;
+        self.place(coord).ok()?;
+        self.undone.pop();
+        return Some((coord, cell_type));
+    }
+
+    fn place(&mut self, coord: Coord) -> Result<(), MoveError> {
+        let cell_type = self.side_to_move();
+        self.check_move(&coord, cell_type)?;
+
+        let position = board::coord_to_position(&coord, self.board.width);
+        self.board[position] = cell_type;
+        self.history.push((coord, cell_type));
+        return Ok(());
+    }
+
+    fn check_move(&self, coord: &Coord, cell_type: CellType) -> Result<(), MoveError> {
+        if self.board.is_game_over() != GameOverType::PLAYING {
+            return Err(MoveError::GAME_OVER);
+        }
+
+        if cell_type != self.side_to_move() {
+            return Err(MoveError::WRONG_PLAYER);
+        }
+
+        return board::check_cell(&self.board, coord);
+    }
+}
+
+impl Game for GameState {
+    fn get_board_state(&self) -> Board {
+        return self.board.clone();
+    }
+
+    fn is_game_over(&self) -> GameOverType {
+        return self.board.is_game_over();
+    }
+
+    fn set_player(&self, coord: Coord, cell_type: CellType) -> Box<dyn Game> {
+        match self.try_set_player(coord, cell_type) {
+            Ok(game) => return game,
+            Err(_) => return Box::new(self.clone()),
+        }
+    }
+
+    fn try_set_player(
+        &self,
+        coord: Coord,
+        cell_type: CellType,
+    ) -> Result<Box<dyn Game>, MoveError> {
+        self.check_move(&coord, cell_type)?;
+
+        let mut game_state = self.clone();
+        game_state.play(coord)?;
+        return Ok(Box::new(game_state));
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod game_tree_tests {
+    use crate::board::{self, game_tree, Board, CellType, TreeStatistics};
+
+    #[test]
+    fn given_empty_board_then_count_every_game() {
+        let statistics = game_tree::enumerate(board::new(), CellType::PLAYER_1);
+
+        assert_eq!(
+            statistics,
+            TreeStatistics {
+                games: 255_168,
+                player_1_wins: 131_184,
+                player_2_wins: 77_904,
+                draws: 46_080,
+                positions_per_depth: vec![1, 9, 72, 252, 756, 1260, 1520, 1140, 390, 78],
+                unique_positions: 765,
+            }
+        );
+        assert_eq!(statistics.positions(), 5478);
+    }
+
+    #[test]
+    fn given_player_2_first_then_swap_wins() {
+        let statistics = game_tree::enumerate(board::new(), CellType::PLAYER_2);
+
+        assert_eq!(statistics.games, 255_168);
+        assert_eq!(statistics.player_1_wins, 77_904);
+        assert_eq!(statistics.player_2_wins, 131_184);
+    }
+
+    #[test]
+    fn given_finished_game_then_count_one_game() {
+        let game_board: Board = "XXX/OO./...".parse().unwrap();
+
+        let statistics = game_tree::enumerate(Box::new(game_board), CellType::PLAYER_2);
+
+        assert_eq!(statistics.games, 1);
+        assert_eq!(statistics.player_1_wins, 1);
+        assert_eq!(statistics.positions_per_depth, vec![1]);
+    }
+
+    #[test]
+    fn given_one_empty_cell_then_count_one_draw() {
+        let game_board: Board = "XOX/XOO/OX.".parse().unwrap();
+
+        let statistics = game_tree::enumerate(Box::new(game_board), CellType::PLAYER_1);
+
+        assert_eq!(statistics.games, 1);
+        assert_eq!(statistics.draws, 1);
+        assert_eq!(statistics.positions_per_depth, vec![1, 1]);
+    }
+}
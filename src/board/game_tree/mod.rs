@@ -0,0 +1,114 @@
+mod game_tree_tests;
+
+use crate::board::{symmetry, CellType, Game, GameOverType};
+use std::collections::{HashMap, HashSet};
+
+/// Counts gathered by playing out every legal game from a position. On the
+/// empty 3x3 board they can be checked against the well known totals, such
+/// as 255,168 games and 5,478 positions.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TreeStatistics {
+    pub games: u64,
+    pub player_1_wins: u64,
+    pub player_2_wins: u64,
+    pub draws: u64,
+    /// Distinct positions after each number of moves, starting with the
+    /// position the walk began from.
+    pub positions_per_depth: Vec<usize>,
+    /// Distinct positions once rotations and reflections of a board are
+    /// counted as one.
+    pub unique_positions: usize,
+}
+
+impl TreeStatistics {
+    /// Distinct positions over all depths.
+    pub fn positions(&self) -> usize {
+        return self.positions_per_depth.iter().sum();
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct GameCounts {
+    games: u64,
+    player_1_wins: u64,
+    player_2_wins: u64,
+    draws: u64,
+}
+
+impl GameCounts {
+    fn add(&mut self, other: &GameCounts) {
+        self.games += other.games;
+        self.player_1_wins += other.player_1_wins;
+        self.player_2_wins += other.player_2_wins;
+        self.draws += other.draws;
+    }
+}
+
+#[derive(Default)]
+struct Walker {
+    counts: HashMap<Vec<i8>, GameCounts>,
+    canonical_positions: HashSet<Vec<i8>>,
+    positions_per_depth: Vec<usize>,
+}
+
+impl Walker {
+    /// Counts the games that go on from `game_board`. Each position is only
+    /// expanded once, as the games after it do not depend on how it was
+    /// reached.
+    fn walk(&mut self, game_board: Box<dyn Game>, to_move: CellType, depth: usize) -> GameCounts {
+        let board_state = game_board.get_board_state();
+        let key: Vec<i8> = board_state.iter().map(|cell| *cell as i8).collect();
+        if let Some(counts) = self.counts.get(&key) {
+            return *counts;
+        }
+
+        if self.positions_per_depth.len() <= depth {
+            self.positions_per_depth.push(0);
+        }
+        self.positions_per_depth[depth] += 1;
+        self.canonical_positions
+            .insert(symmetry::canonical_cells(&board_state).0);
+
+        let mut counts = GameCounts::default();
+        match game_board.is_game_over() {
+            GameOverType::PLAYING => {
+                for position in 0..board_state.len() {
+                    if board_state[position] != CellType::NON {
+                        continue;
+                    }
+
+                    let next_game_board =
+                        game_board.set_player(board_state.coord_of(position), to_move);
+                    counts.add(&self.walk(next_game_board, to_move.opponent(), depth + 1));
+                }
+            }
+            result => {
+                counts.games = 1;
+                match result {
+                    GameOverType::PLAYER_1_WIN => counts.player_1_wins = 1,
+                    GameOverType::PLAYER_2_WIN => counts.player_2_wins = 1,
+                    _ => counts.draws = 1,
+                }
+            }
+        }
+
+        self.counts.insert(key, counts);
+        return counts;
+    }
+}
+
+/// Plays out every legal game from `game_board`, with `to_move` making the
+/// first move, and counts games, results and positions.
+pub fn enumerate(game_board: Box<dyn Game>, to_move: CellType) -> TreeStatistics {
+    let mut walker = Walker::default();
+    let counts = walker.walk(game_board, to_move, 0);
+
+    return TreeStatistics {
+        games: counts.games,
+        player_1_wins: counts.player_1_wins,
+        player_2_wins: counts.player_2_wins,
+        draws: counts.draws,
+        positions_per_depth: walker.positions_per_depth,
+        unique_positions: walker.canonical_positions.len(),
+    };
+}
@@ -1,15 +1,49 @@
+pub mod bitboard;
 mod board_tests;
+mod game_state;
+pub mod game_tree;
+pub mod notation;
+mod outcome;
+mod serde_tests;
+pub mod symmetry;
+pub mod validation;
+
+pub use bitboard::BitBoard;
+pub use game_state::{GameRecord, GameState};
+pub use game_tree::TreeStatistics;
+pub use notation::ParseError;
+pub use outcome::GameOutcome;
+pub use symmetry::Symmetry;
+pub use validation::PositionError;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+use std::{error, fmt};
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CellType {
     NON = 0,
     PLAYER_1 = 1,
     PLAYER_2 = -1,
 }
 
+impl CellType {
+    /// The player who moves after this one. `NON` has no opponent.
+    pub fn opponent(&self) -> CellType {
+        match self {
+            CellType::PLAYER_1 => CellType::PLAYER_2,
+            CellType::PLAYER_2 => CellType::PLAYER_1,
+            CellType::NON => CellType::NON,
+        }
+    }
+}
+
 #[allow(non_camel_case_types)]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GameOverType {
     PLAYING = 0,
     PLAYER_1_WIN = 1,
@@ -17,9 +51,173 @@ pub enum GameOverType {
     DRAW = 2,
 }
 
-pub type Board = Vec<CellType>;
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MoveError {
+    OCCUPIED_CELL,
+    OUT_OF_BOUNDS,
+    WRONG_PLAYER,
+    GAME_OVER,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            MoveError::OCCUPIED_CELL => "the cell is already occupied",
+            MoveError::OUT_OF_BOUNDS => "the coordinate is outside the board",
+            MoveError::WRONG_PLAYER => "it is not this player's turn",
+            MoveError::GAME_OVER => "the game is already over",
+        };
+        return write!(f, "{}", message);
+    }
+}
+
+impl error::Error for MoveError {}
+
+/// A `width` x `height` grid of cells, stored row by row. A player wins by
+/// filling `win_length` cells in a row, column or diagonal.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "RawBoard")
+)]
+pub struct Board {
+    pub width: usize,
+    pub height: usize,
+    pub win_length: usize,
+    pub cells: Vec<CellType>,
+}
+
+/// A `Board` as it is deserialized, before its shape is checked.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawBoard {
+    width: usize,
+    height: usize,
+    win_length: usize,
+    cells: Vec<CellType>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawBoard> for Board {
+    type Error = PositionError;
+
+    fn try_from(raw: RawBoard) -> Result<Board, PositionError> {
+        let board = Board {
+            width: raw.width,
+            height: raw.height,
+            win_length: raw.win_length,
+            cells: raw.cells,
+        };
+        board.validate_shape()?;
+        return Ok(board);
+    }
+}
+
+/// `length` cells starting at position `start`, each `step` positions after
+/// the previous one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line {
+    pub start: usize,
+    pub step: usize,
+    pub length: usize,
+}
+
+impl Line {
+    pub fn positions(&self) -> impl Iterator<Item = usize> {
+        let Line {
+            start,
+            step,
+            length,
+        } = *self;
+        return (0..length).map(move |index| start + index * step);
+    }
+}
+
+impl Board {
+    /// Creates an empty board. Panics when no line of `win_length` cells fits
+    /// on it.
+    pub fn new(width: usize, height: usize, win_length: usize) -> Board {
+        assert!(
+            win_length >= 1 && win_length <= width.max(height),
+            "a {}x{} board has no line of {} cells",
+            width,
+            height,
+            win_length
+        );
+
+        return Board {
+            width,
+            height,
+            win_length,
+            cells: vec![CellType::NON; width * height],
+        };
+    }
+
+    /// Every line of `win_length` cells on the board: rows first, then
+    /// columns, then both diagonal directions.
+    pub fn lines(&self) -> impl Iterator<Item = Line> {
+        let (width, height, length) = (self.width as isize, self.height as isize, self.win_length);
+        let directions: &[(isize, isize)] = match length {
+            1 => &[(1, 0)],
+            _ => &[(1, 0), (0, 1), (1, 1), (-1, 1)],
+        };
+        let reach = length as isize - 1;
+
+        return directions.iter().flat_map(move |&(dx, dy)| {
+            (0..height).flat_map(move |y| {
+                (0..width).filter_map(move |x| {
+                    let (end_x, end_y) = (x + dx * reach, y + dy * reach);
+                    if !(0..width).contains(&end_x) || !(0..height).contains(&end_y) {
+                        return None;
+                    }
+
+                    return Some(Line {
+                        start: (y * width + x) as usize,
+                        step: (dy * width + dx) as usize,
+                        length,
+                    });
+                })
+            })
+        });
+    }
+
+    pub fn is_in_bounds(&self, coord: &Coord) -> bool {
+        return (0..self.width as isize).contains(&coord.x)
+            && (0..self.height as isize).contains(&coord.y);
+    }
+
+    /// The position of `coord` in `cells`, or `None` when it lies outside the
+    /// board.
+    pub fn position_of(&self, coord: &Coord) -> Option<usize> {
+        if !self.is_in_bounds(coord) {
+            return None;
+        }
+        return Some(coord_to_position(coord, self.width));
+    }
+
+    pub fn coord_of(&self, position: usize) -> Coord {
+        return position_to_coord(position, self.width);
+    }
+}
+
+impl Deref for Board {
+    type Target = [CellType];
 
-#[derive(Debug, PartialEq)]
+    fn deref(&self) -> &[CellType] {
+        return &self.cells;
+    }
+}
+
+impl DerefMut for Board {
+    fn deref_mut(&mut self) -> &mut [CellType] {
+        return &mut self.cells;
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Coord {
     pub x: isize,
     pub y: isize,
@@ -29,6 +227,13 @@ pub trait Game {
     fn get_board_state(&self) -> Board;
     fn is_game_over(&self) -> GameOverType;
     fn set_player(&self, coord: Coord, cell_type: CellType) -> Box<dyn Game>;
+    fn try_set_player(&self, coord: Coord, cell_type: CellType)
+        -> Result<Box<dyn Game>, MoveError>;
+
+    /// Like `is_game_over`, with the coordinates of the winning lines.
+    fn get_outcome(&self) -> GameOutcome {
+        return self.get_board_state().outcome();
+    }
 }
 
 impl Game for Board {
@@ -37,21 +242,8 @@ impl Game for Board {
     }
 
     fn is_game_over(&self) -> GameOverType {
-        let pattern_check_list: Vec<Vec<usize>> = vec![
-            vec![0, 1, 2],
-            vec![3, 4, 5],
-            vec![6, 7, 8],
-            vec![0, 3, 6],
-            vec![1, 4, 7],
-            vec![2, 5, 8],
-            vec![0, 4, 8],
-            vec![2, 4, 6],
-        ];
-
-        for pattern in pattern_check_list {
-            let (p0, p1, p2) = (pattern[0], pattern[1], pattern[2]);
-
-            let victor = match get_victor([self[p0], self[p1], self[p2]]) {
+        for line in self.lines() {
+            let victor = match get_victor(self, &line) {
                 CellType::NON => GameOverType::PLAYING,
                 CellType::PLAYER_1 => GameOverType::PLAYER_1_WIN,
                 CellType::PLAYER_2 => GameOverType::PLAYER_2_WIN,
@@ -71,32 +263,90 @@ impl Game for Board {
     fn set_player(&self, coord: Coord, cell_type: CellType) -> Box<dyn Game> {
         return Box::new(update_cell_type(self, coord, cell_type));
     }
+
+    fn try_set_player(
+        &self,
+        coord: Coord,
+        cell_type: CellType,
+    ) -> Result<Box<dyn Game>, MoveError> {
+        check_move(self, &coord, cell_type)?;
+        return Ok(self.set_player(coord, cell_type));
+    }
 }
 
-#[allow(dead_code)]
+/// Creates an empty 3x3 board with three in a row to win.
 pub fn new() -> Box<dyn Game> {
-    Box::new(vec![CellType::NON; 9])
+    return new_with_size(3, 3, 3);
 }
 
-fn get_victor(cells: [CellType; 3]) -> CellType {
-    let p0_cell_type = cells[0];
-    if p0_cell_type == cells[1] && p0_cell_type == cells[2] {
+/// Creates an empty `width` x `height` board with `win_length` in a row to
+/// win.
+pub fn new_with_size(width: usize, height: usize, win_length: usize) -> Box<dyn Game> {
+    return Box::new(Board::new(width, height, win_length));
+}
+
+fn get_victor(board: &Board, line: &Line) -> CellType {
+    let p0_cell_type = board[line.start];
+    if line
+        .positions()
+        .all(|position| board[position] == p0_cell_type)
+    {
         return p0_cell_type;
     }
     return CellType::NON;
 }
 
-fn coord_to_position(coord: &Coord) -> usize {
-    return ((coord.y * 3) + coord.x) as usize;
+fn coord_to_position(coord: &Coord, width: usize) -> usize {
+    return ((coord.y * width as isize) + coord.x) as usize;
+}
+
+fn position_to_coord(position: usize, width: usize) -> Coord {
+    let x = (position % width) as isize;
+    let y = (position / width) as isize;
+    return Coord { x, y };
+}
+
+/// Checks that `cell_type` may be placed on `coord`. As a plain board does not
+/// know who started, a player may not move once they have more marks than
+/// their opponent.
+fn check_move(board: &Board, coord: &Coord, cell_type: CellType) -> Result<(), MoveError> {
+    if board.is_game_over() != GameOverType::PLAYING {
+        return Err(MoveError::GAME_OVER);
+    }
+
+    let count_of = |cell_type| board.iter().filter(|&cell| *cell == cell_type).count();
+    if cell_type == CellType::NON || count_of(cell_type) > count_of(cell_type.opponent()) {
+        return Err(MoveError::WRONG_PLAYER);
+    }
+
+    return check_cell(board, coord);
+}
+
+/// Checks that `coord` is an empty cell of `board`.
+fn check_cell(board: &Board, coord: &Coord) -> Result<(), MoveError> {
+    let position = match board.position_of(coord) {
+        Some(position) => position,
+        None => return Err(MoveError::OUT_OF_BOUNDS),
+    };
+
+    if board[position] != CellType::NON {
+        return Err(MoveError::OCCUPIED_CELL);
+    }
+
+    return Ok(());
 }
 
 fn update_cell_type(board: &Board, coord: Coord, cell_type: CellType) -> Board {
-    let position = coord_to_position(&coord);
+    let position = match board.position_of(&coord) {
+        Some(position) => position,
+        None => return board.clone(),
+    };
+
     if board[position] != CellType::NON {
-        return board.to_vec();
+        return board.clone();
     }
 
     let mut updated_board = board.clone();
     updated_board[position] = cell_type;
-    return updated_board.to_vec();
+    return updated_board;
 }
@@ -0,0 +1,166 @@
+//! Compact text notation for positions. Rows are written top to bottom and
+//! separated by `/`, with `X` for player 1, `O` for player 2 and `.` for an
+//! empty cell: `X.O/.X./..O`. When the win length differs from the shorter
+//! side of the board it follows as a number (`..../..../.... 3`). A
+//! `GameState` adds the side to move at the end (`X.O/.X./..O o`).
+
+mod notation_tests;
+
+use crate::board::{Board, CellType, Game, GameState};
+use std::iter::Peekable;
+use std::str::{FromStr, SplitWhitespace};
+use std::{error, fmt};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParseError {
+    EMPTY,
+    INVALID_CELL(char),
+    UNEVEN_ROWS,
+    INVALID_WIN_LENGTH(String),
+    MISSING_SIDE_TO_MOVE,
+    INVALID_SIDE_TO_MOVE(String),
+    UNEXPECTED_TOKEN(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::EMPTY => write!(f, "the position is empty"),
+            ParseError::INVALID_CELL(cell) => {
+                write!(f, "`{}` is not a cell, expected X, O or .", cell)
+            }
+            ParseError::UNEVEN_ROWS => write!(f, "the rows do not all have the same length"),
+            ParseError::INVALID_WIN_LENGTH(token) => {
+                write!(f, "`{}` is not a win length that fits the board", token)
+            }
+            ParseError::MISSING_SIDE_TO_MOVE => write!(f, "the side to move is missing"),
+            ParseError::INVALID_SIDE_TO_MOVE(token) => {
+                write!(f, "`{}` is not a side to move, expected x or o", token)
+            }
+            ParseError::UNEXPECTED_TOKEN(token) => write!(f, "unexpected `{}`", token),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<String> = self
+            .chunks(self.width)
+            .map(|row| row.iter().map(|cell| cell_to_char(*cell)).collect())
+            .collect();
+        write!(f, "{}", rows.join("/"))?;
+
+        if self.win_length != self.width.min(self.height) {
+            write!(f, " {}", self.win_length)?;
+        }
+        return Ok(());
+    }
+}
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(notation: &str) -> Result<Board, ParseError> {
+        let mut tokens = notation.split_whitespace().peekable();
+        let board = parse_board(&mut tokens)?;
+
+        match tokens.next() {
+            Some(token) => return Err(ParseError::UNEXPECTED_TOKEN(token.to_string())),
+            None => return Ok(board),
+        }
+    }
+}
+
+impl fmt::Display for GameState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let side_to_move = match self.side_to_move() {
+            CellType::PLAYER_2 => 'o',
+            _ => 'x',
+        };
+        return write!(f, "{} {}", self.get_board_state(), side_to_move);
+    }
+}
+
+impl FromStr for GameState {
+    type Err = ParseError;
+
+    fn from_str(notation: &str) -> Result<GameState, ParseError> {
+        let mut tokens = notation.split_whitespace().peekable();
+        let board = parse_board(&mut tokens)?;
+
+        let side_to_move = match tokens.next() {
+            Some("x") | Some("X") => CellType::PLAYER_1,
+            Some("o") | Some("O") => CellType::PLAYER_2,
+            Some(token) => return Err(ParseError::INVALID_SIDE_TO_MOVE(token.to_string())),
+            None => return Err(ParseError::MISSING_SIDE_TO_MOVE),
+        };
+
+        match tokens.next() {
+            Some(token) => return Err(ParseError::UNEXPECTED_TOKEN(token.to_string())),
+            None => return Ok(GameState::from_board(board, side_to_move)),
+        }
+    }
+}
+
+/// Reads the rows and, if the next token is a number, the win length. Only
+/// consumes the win length token when it is one.
+fn parse_board(tokens: &mut Peekable<SplitWhitespace>) -> Result<Board, ParseError> {
+    let rows: Vec<Vec<CellType>> = match tokens.next() {
+        Some(rows) => rows
+            .split('/')
+            .map(|row| row.chars().map(char_to_cell).collect())
+            .collect::<Result<_, _>>()?,
+        None => return Err(ParseError::EMPTY),
+    };
+
+    let width = rows[0].len();
+    if width == 0 {
+        return Err(ParseError::EMPTY);
+    }
+    if rows.iter().any(|row| row.len() != width) {
+        return Err(ParseError::UNEVEN_ROWS);
+    }
+    let height = rows.len();
+
+    let mut win_length = width.min(height);
+    if let Some(token) = tokens.peek() {
+        if token.chars().all(|c| c.is_ascii_digit()) {
+            win_length = match token.parse::<usize>() {
+                Ok(value) if value >= 1 && value <= width.max(height) => value,
+                _ => return Err(ParseError::INVALID_WIN_LENGTH(token.to_string())),
+            };
+            tokens.next();
+        }
+    }
+
+    let mut board = Board::new(width, height, win_length);
+    board.cells = rows.concat();
+    return Ok(board);
+}
+
+fn char_to_cell(cell: char) -> Result<CellType, ParseError> {
+    match cell {
+        'X' | 'x' => Ok(CellType::PLAYER_1),
+        'O' | 'o' => Ok(CellType::PLAYER_2),
+        '.' => Ok(CellType::NON),
+        _ => Err(ParseError::INVALID_CELL(cell)),
+    }
+}
+
+/// `X`, `O` or `.`, as in the notation.
+impl fmt::Display for CellType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", cell_to_char(*self));
+    }
+}
+
+fn cell_to_char(cell_type: CellType) -> char {
+    match cell_type {
+        CellType::PLAYER_1 => 'X',
+        CellType::PLAYER_2 => 'O',
+        CellType::NON => '.',
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod notation_tests {
+    use crate::board::{self, Board, CellType, Coord, Game, GameState, ParseError};
+
+    #[test]
+    fn should_parse_board_notation() {
+        let board: Board = "X.O/.X./..O".parse().unwrap();
+
+        let expected_board = board::new()
+            .set_player(Coord { x: 0, y: 0 }, CellType::PLAYER_1)
+            .set_player(Coord { x: 2, y: 0 }, CellType::PLAYER_2)
+            .set_player(Coord { x: 1, y: 1 }, CellType::PLAYER_1)
+            .set_player(Coord { x: 2, y: 2 }, CellType::PLAYER_2)
+            .get_board_state();
+        assert_eq!(board, expected_board);
+    }
+
+    #[test]
+    fn should_print_board_notation() {
+        let board = board::new()
+            .set_player(Coord { x: 1, y: 0 }, CellType::PLAYER_2)
+            .set_player(Coord { x: 2, y: 2 }, CellType::PLAYER_1)
+            .get_board_state();
+
+        assert_eq!(board.to_string(), ".O./.../..X");
+    }
+
+    #[test]
+    fn should_print_cell_types_as_in_notation() {
+        assert_eq!(CellType::PLAYER_1.to_string(), "X");
+        assert_eq!(CellType::PLAYER_2.to_string(), "O");
+        assert_eq!(CellType::NON.to_string(), ".");
+    }
+
+    #[test]
+    fn should_round_trip_board_size_and_win_length() {
+        for notation in ["X.../..../.O..", "...../...../...../..... 3", "X.O.O 2"] {
+            let board: Board = notation.parse().unwrap();
+
+            assert_eq!(board.to_string(), notation);
+        }
+
+        let board: Board = "X.../..../.O..".parse().unwrap();
+        assert_eq!((board.width, board.height, board.win_length), (4, 3, 3));
+    }
+
+    #[test]
+    fn should_parse_and_print_side_to_move() {
+        let game_state: GameState = "XO./.X./... o".parse().unwrap();
+
+        assert_eq!(game_state.side_to_move(), CellType::PLAYER_2);
+        assert_eq!(game_state.get_board_state().to_string(), "XO./.X./...");
+        assert_eq!(game_state.to_string(), "XO./.X./... o");
+
+        let game_state: GameState = "..../..../..../.... 3 X".parse().unwrap();
+        assert_eq!(game_state.side_to_move(), CellType::PLAYER_1);
+        assert_eq!(game_state.to_string(), "..../..../..../.... 3 x");
+    }
+
+    #[test]
+    fn should_return_parse_errors() {
+        assert_eq!("".parse::<Board>(), Err(ParseError::EMPTY));
+        assert_eq!("/".parse::<Board>(), Err(ParseError::EMPTY));
+        assert_eq!(
+            "X.Z/.../...".parse::<Board>(),
+            Err(ParseError::INVALID_CELL('Z'))
+        );
+        assert_eq!("X../../...".parse::<Board>(), Err(ParseError::UNEVEN_ROWS));
+        assert_eq!(
+            ".../.../... 4".parse::<Board>(),
+            Err(ParseError::INVALID_WIN_LENGTH(String::from("4")))
+        );
+        assert_eq!(
+            ".../.../... x".parse::<Board>(),
+            Err(ParseError::UNEXPECTED_TOKEN(String::from("x")))
+        );
+        assert_eq!(
+            ".../.../...".parse::<GameState>(),
+            Err(ParseError::MISSING_SIDE_TO_MOVE)
+        );
+        assert_eq!(
+            ".../.../... z".parse::<GameState>(),
+            Err(ParseError::INVALID_SIDE_TO_MOVE(String::from("z")))
+        );
+    }
+}
@@ -0,0 +1,61 @@
+mod outcome_tests;
+
+use crate::board::{Board, CellType, Coord, Game, GameOverType};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How a game stands, together with the lines that decided it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameOutcome {
+    pub result: GameOverType,
+    /// The cells of every line the winner completed, in `Board::lines` order.
+    /// A last move can complete more than one line at once. Empty unless the
+    /// game was won.
+    pub winning_lines: Vec<Vec<Coord>>,
+}
+
+impl GameOutcome {
+    /// Every cell of the winning lines, each listed once.
+    pub fn winning_cells(&self) -> Vec<Coord> {
+        let mut cells: Vec<Coord> = vec![];
+        for coord in self.winning_lines.iter().flatten() {
+            if !cells.contains(coord) {
+                cells.push(*coord);
+            }
+        }
+        return cells;
+    }
+}
+
+impl Board {
+    /// Like `is_game_over`, with the coordinates of the winning lines.
+    pub fn outcome(&self) -> GameOutcome {
+        let result = self.is_game_over();
+        let winner = match result {
+            GameOverType::PLAYER_1_WIN => CellType::PLAYER_1,
+            GameOverType::PLAYER_2_WIN => CellType::PLAYER_2,
+            _ => {
+                return GameOutcome {
+                    result,
+                    winning_lines: vec![],
+                }
+            }
+        };
+
+        let winning_lines = self
+            .lines()
+            .filter(|line| line.positions().all(|position| self[position] == winner))
+            .map(|line| {
+                line.positions()
+                    .map(|position| self.coord_of(position))
+                    .collect()
+            })
+            .collect();
+
+        return GameOutcome {
+            result,
+            winning_lines,
+        };
+    }
+}
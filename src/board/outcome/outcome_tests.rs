@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod outcome_tests {
+    use crate::board::{self, Board, Coord, Game, GameOutcome, GameOverType, GameState};
+
+    #[test]
+    fn given_game_in_progress_then_report_no_lines() {
+        let outcome = board::new().get_outcome();
+
+        assert_eq!(
+            outcome,
+            GameOutcome {
+                result: GameOverType::PLAYING,
+                winning_lines: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn given_won_game_then_report_winning_line() {
+        let game_board: Board = "OO./XXX/...".parse().unwrap();
+
+        let outcome = game_board.get_outcome();
+
+        assert_eq!(outcome.result, GameOverType::PLAYER_1_WIN);
+        assert_eq!(
+            outcome.winning_lines,
+            vec![vec![
+                Coord { x: 0, y: 1 },
+                Coord { x: 1, y: 1 },
+                Coord { x: 2, y: 1 }
+            ]]
+        );
+    }
+
+    #[test]
+    fn given_last_move_completing_two_lines_then_report_both() {
+        let game_board: Board = "XXX/OXO/XOO".parse().unwrap();
+
+        let outcome = game_board.get_outcome();
+
+        assert_eq!(outcome.result, GameOverType::PLAYER_1_WIN);
+        assert_eq!(
+            outcome.winning_lines,
+            vec![
+                vec![
+                    Coord { x: 0, y: 0 },
+                    Coord { x: 1, y: 0 },
+                    Coord { x: 2, y: 0 }
+                ],
+                vec![
+                    Coord { x: 2, y: 0 },
+                    Coord { x: 1, y: 1 },
+                    Coord { x: 0, y: 2 }
+                ],
+            ]
+        );
+        assert_eq!(outcome.winning_cells().len(), 5);
+    }
+
+    #[test]
+    fn given_draw_then_report_no_lines() {
+        let game_board: Board = "XOX/XOO/OXX".parse().unwrap();
+
+        let outcome = game_board.get_outcome();
+
+        assert_eq!(outcome.result, GameOverType::DRAW);
+        assert!(outcome.winning_lines.is_empty());
+    }
+
+    #[test]
+    fn given_other_backends_then_report_same_outcome() {
+        let game_state: GameState = ".../OO./XXX o".parse().unwrap();
+        let bit_board = board::BitBoard::from_board(&game_state.get_board_state()).unwrap();
+
+        assert_eq!(
+            game_state.get_outcome(),
+            game_state.get_board_state().outcome()
+        );
+        assert_eq!(
+            bit_board.get_outcome(),
+            game_state.get_board_state().outcome()
+        );
+        assert_eq!(game_state.get_outcome().result, GameOverType::PLAYER_1_WIN);
+    }
+}
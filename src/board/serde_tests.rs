@@ -0,0 +1,99 @@
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use crate::board::{Board, CellType, Coord, Game, GameOverType, GameRecord, GameState};
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use std::fmt::Debug;
+
+    fn assert_round_trips<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T) {
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<T>(&bytes).unwrap(), value);
+    }
+
+    fn played_game() -> GameState {
+        let mut game_state = GameState::new(CellType::PLAYER_2);
+        for (x, y) in [
+            (1, 1),
+            (0, 0),
+            (2, 0),
+            (0, 2),
+            (0, 1),
+            (2, 1),
+            (1, 0),
+            (1, 2),
+            (2, 2),
+        ] {
+            game_state.play(Coord { x, y }).unwrap();
+        }
+        return game_state;
+    }
+
+    #[test]
+    fn should_round_trip_cell_types_and_results() {
+        for cell_type in [CellType::NON, CellType::PLAYER_1, CellType::PLAYER_2] {
+            assert_round_trips(cell_type);
+        }
+
+        for result in [
+            GameOverType::PLAYING,
+            GameOverType::PLAYER_1_WIN,
+            GameOverType::PLAYER_2_WIN,
+            GameOverType::DRAW,
+        ] {
+            assert_round_trips(result);
+        }
+    }
+
+    #[test]
+    fn should_round_trip_coords_and_boards() {
+        assert_round_trips(Coord { x: 2, y: -1 });
+        assert_round_trips("X.O./.X../...O 3".parse::<Board>().unwrap());
+    }
+
+    #[test]
+    fn should_serialize_board_to_readable_json() {
+        let board: Board = "X../.O./...".parse().unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&board).unwrap(),
+            r#"{"width":3,"height":3,"win_length":3,"cells":["PLAYER_1","NON","NON","NON","PLAYER_2","NON","NON","NON","NON"]}"#
+        );
+    }
+
+    #[test]
+    fn should_reject_board_of_wrong_shape() {
+        let wrong_length = r#"{"width":3,"height":3,"win_length":3,"cells":["NON","NON"]}"#;
+        let no_win_length = r#"{"width":1,"height":1,"win_length":0,"cells":["NON"]}"#;
+
+        assert!(serde_json::from_str::<Board>(wrong_length).is_err());
+        assert!(serde_json::from_str::<Board>(no_win_length).is_err());
+
+        let board = Board {
+            width: 2,
+            height: 2,
+            win_length: 3,
+            cells: vec![CellType::NON; 4],
+        };
+        let bytes = bincode::serialize(&board).unwrap();
+        assert!(bincode::deserialize::<Board>(&bytes).is_err());
+    }
+
+    #[test]
+    fn should_round_trip_game_record() {
+        let game_state = played_game();
+        let record = game_state.record();
+
+        assert_round_trips(record.clone());
+
+        let json = serde_json::to_string(&record).unwrap();
+        let replayed = serde_json::from_str::<GameRecord>(&json)
+            .unwrap()
+            .replay()
+            .unwrap();
+        assert_eq!(replayed, game_state);
+        assert_eq!(replayed.is_game_over(), record.result);
+    }
+}
@@ -0,0 +1,140 @@
+//! The rotations and reflections of a board, for caching, de-duplicating or
+//! comparing positions that only differ by orientation.
+
+mod symmetry_tests;
+
+use crate::board::{Board, Coord};
+
+/// One of the eight rotations and reflections of a board. Rotations turn
+/// clockwise, `MIRROR_X` flips the x coordinate and `TRANSPOSE` swaps x and
+/// y. On a board that is not square, the transforms that swap the width and
+/// height give a board of another shape.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Symmetry {
+    IDENTITY,
+    ROTATE_90,
+    ROTATE_180,
+    ROTATE_270,
+    MIRROR_X,
+    MIRROR_Y,
+    TRANSPOSE,
+    ANTI_TRANSPOSE,
+}
+
+pub const SYMMETRIES: [Symmetry; 8] = [
+    Symmetry::IDENTITY,
+    Symmetry::ROTATE_90,
+    Symmetry::ROTATE_180,
+    Symmetry::ROTATE_270,
+    Symmetry::MIRROR_X,
+    Symmetry::MIRROR_Y,
+    Symmetry::TRANSPOSE,
+    Symmetry::ANTI_TRANSPOSE,
+];
+
+impl Symmetry {
+    /// Whether the transformed board has the width and height swapped.
+    pub fn swaps_dimensions(&self) -> bool {
+        match self {
+            Symmetry::IDENTITY | Symmetry::ROTATE_180 | Symmetry::MIRROR_X | Symmetry::MIRROR_Y => {
+                return false
+            }
+            _ => return true,
+        }
+    }
+
+    /// Whether transforming `board` keeps its shape, so that the result can
+    /// be compared with `board`.
+    pub fn is_valid_for(&self, board: &Board) -> bool {
+        return !self.swaps_dimensions() || board.width == board.height;
+    }
+
+    /// The symmetry that undoes this one.
+    pub fn inverse(&self) -> Symmetry {
+        match self {
+            Symmetry::ROTATE_90 => Symmetry::ROTATE_270,
+            Symmetry::ROTATE_270 => Symmetry::ROTATE_90,
+            symmetry => *symmetry,
+        }
+    }
+
+    /// Where the cell at `position` of `board` ends up once the board is
+    /// transformed.
+    pub fn apply(&self, board: &Board, position: usize) -> usize {
+        let Coord { x, y } = self.apply_to_coord(board, board.coord_of(position));
+        let width = if self.swaps_dimensions() {
+            board.height
+        } else {
+            board.width
+        };
+        return y as usize * width + x as usize;
+    }
+
+    /// Where the cell at `coord` of `board` ends up once the board is
+    /// transformed. `coord` must be on the board.
+    pub fn apply_to_coord(&self, board: &Board, coord: Coord) -> Coord {
+        let Coord { x, y } = coord;
+        let (max_x, max_y) = (board.width as isize - 1, board.height as isize - 1);
+
+        let (x, y) = match self {
+            Symmetry::IDENTITY => (x, y),
+            Symmetry::ROTATE_90 => (max_y - y, x),
+            Symmetry::ROTATE_180 => (max_x - x, max_y - y),
+            Symmetry::ROTATE_270 => (y, max_x - x),
+            Symmetry::MIRROR_X => (max_x - x, y),
+            Symmetry::MIRROR_Y => (x, max_y - y),
+            Symmetry::TRANSPOSE => (y, x),
+            Symmetry::ANTI_TRANSPOSE => (max_y - y, max_x - x),
+        };
+        return Coord { x, y };
+    }
+
+    /// A copy of `board` with every cell moved to where this symmetry takes
+    /// it.
+    pub fn apply_to_board(&self, board: &Board) -> Board {
+        let mut transformed = if self.swaps_dimensions() {
+            Board::new(board.height, board.width, board.win_length)
+        } else {
+            Board::new(board.width, board.height, board.win_length)
+        };
+
+        for (position, cell) in board.iter().enumerate() {
+            transformed[self.apply(board, position)] = *cell;
+        }
+        return transformed;
+    }
+}
+
+/// The orientation of `board` that all of its rotations and reflections share,
+/// together with the symmetry that takes `board` to it. Boards that are not
+/// square are only compared with the orientations of the same shape.
+pub fn canonical(board: &Board) -> (Board, Symmetry) {
+    let (_, symmetry) = canonical_cells(board);
+    return (symmetry.apply_to_board(board), symmetry);
+}
+
+/// The cells of `board` as `i8`s in the orientation with the smallest
+/// encoding over all of its symmetries, together with the symmetry that
+/// produces it.
+pub(crate) fn canonical_cells(board: &Board) -> (Vec<i8>, Symmetry) {
+    let mut canonical: Option<(Vec<i8>, Symmetry)> = None;
+
+    for symmetry in SYMMETRIES {
+        if !symmetry.is_valid_for(board) {
+            continue;
+        }
+
+        let mut cells = vec![0; board.len()];
+        for (position, cell) in board.iter().enumerate() {
+            cells[symmetry.apply(board, position)] = *cell as i8;
+        }
+
+        match &canonical {
+            Some((smallest, _)) if *smallest <= cells => (),
+            _ => canonical = Some((cells, symmetry)),
+        }
+    }
+
+    return canonical.expect("the identity is valid for every board");
+}
@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod symmetry_tests {
+    use crate::board::symmetry::{self, Symmetry, SYMMETRIES};
+    use crate::board::{Board, CellType, Coord};
+    use crate::random::Random;
+
+    /// Boards of every shape up to 4x4, each with a random mix of cells.
+    fn random_boards(seed: u64) -> Vec<Board> {
+        let mut random = Random::new(seed);
+        let mut boards = vec![];
+
+        for width in 1..=4 {
+            for height in 1..=4 {
+                for _ in 0..20 {
+                    let mut board = Board::new(width, height, 1);
+                    for cell in board.iter_mut() {
+                        *cell = [CellType::NON, CellType::PLAYER_1, CellType::PLAYER_2]
+                            [random.next_below(3)];
+                    }
+                    boards.push(board);
+                }
+            }
+        }
+        return boards;
+    }
+
+    fn all_coords(board: &Board) -> Vec<Coord> {
+        return (0..board.len())
+            .map(|position| board.coord_of(position))
+            .collect();
+    }
+
+    #[test]
+    fn given_rotation_then_turn_clockwise() {
+        let board: Board = "XO./.../...".parse().unwrap();
+
+        let rotated = Symmetry::ROTATE_90.apply_to_board(&board);
+
+        assert_eq!(rotated, "..X/..O/...".parse::<Board>().unwrap());
+        assert_eq!(
+            Symmetry::ROTATE_90.apply_to_coord(&board, Coord { x: 1, y: 0 }),
+            Coord { x: 2, y: 1 }
+        );
+    }
+
+    #[test]
+    fn given_board_that_is_not_square_then_swap_dimensions() {
+        let board = Board::new(4, 2, 2);
+
+        for symmetry in SYMMETRIES {
+            let transformed = symmetry.apply_to_board(&board);
+
+            if symmetry.swaps_dimensions() {
+                assert_eq!((transformed.width, transformed.height), (2, 4));
+                assert!(!symmetry.is_valid_for(&board));
+            } else {
+                assert_eq!((transformed.width, transformed.height), (4, 2));
+                assert!(symmetry.is_valid_for(&board));
+            }
+        }
+    }
+
+    #[test]
+    fn given_any_board_then_inverse_restores_it() {
+        for board in random_boards(1) {
+            for symmetry in SYMMETRIES {
+                let transformed = symmetry.apply_to_board(&board);
+
+                assert_eq!(symmetry.inverse().apply_to_board(&transformed), board);
+                assert_eq!(symmetry.inverse().inverse(), symmetry);
+            }
+        }
+    }
+
+    #[test]
+    fn given_any_coord_then_inverse_restores_it() {
+        for board in random_boards(2) {
+            for symmetry in SYMMETRIES {
+                let transformed = symmetry.apply_to_board(&board);
+
+                for coord in all_coords(&board) {
+                    let moved = symmetry.apply_to_coord(&board, coord);
+
+                    assert!(transformed.is_in_bounds(&moved));
+                    assert_eq!(
+                        symmetry.inverse().apply_to_coord(&transformed, moved),
+                        coord
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn given_any_coord_then_cell_moves_with_it() {
+        for board in random_boards(3) {
+            for symmetry in SYMMETRIES {
+                let transformed = symmetry.apply_to_board(&board);
+
+                for (position, coord) in all_coords(&board).into_iter().enumerate() {
+                    let moved = symmetry.apply_to_coord(&board, coord);
+
+                    assert_eq!(
+                        transformed.position_of(&moved),
+                        Some(symmetry.apply(&board, position))
+                    );
+                    assert_eq!(
+                        transformed[transformed.position_of(&moved).unwrap()],
+                        board[position]
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn given_any_orientation_then_canonical_form_is_the_same() {
+        for board in random_boards(4) {
+            let (canonical, symmetry) = symmetry::canonical(&board);
+            assert_eq!(symmetry.apply_to_board(&board), canonical);
+
+            for other in SYMMETRIES {
+                if !other.is_valid_for(&board) {
+                    continue;
+                }
+
+                let transformed = other.apply_to_board(&board);
+                let (transformed_canonical, transformed_symmetry) =
+                    symmetry::canonical(&transformed);
+
+                assert_eq!(transformed_canonical, canonical);
+                assert_eq!(transformed_symmetry.apply_to_board(&transformed), canonical);
+            }
+        }
+    }
+
+    #[test]
+    fn given_move_on_canonical_board_then_map_it_back() {
+        let board: Board = "..X/.O./...".parse().unwrap();
+        let (canonical, symmetry) = symmetry::canonical(&board);
+        let canonical_move = Coord { x: 0, y: 2 };
+        assert_eq!(
+            canonical[canonical.position_of(&canonical_move).unwrap()],
+            CellType::NON
+        );
+
+        let real_move = symmetry
+            .inverse()
+            .apply_to_coord(&canonical, canonical_move);
+
+        assert_eq!(symmetry.apply_to_coord(&board, real_move), canonical_move);
+        assert_eq!(board[board.position_of(&real_move).unwrap()], CellType::NON);
+    }
+}
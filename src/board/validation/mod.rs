@@ -0,0 +1,135 @@
+//! Checks that a position could come up in a real game, whichever player
+//! moved first.
+
+mod validation_tests;
+
+use crate::board::{Board, CellType, Line};
+use std::{error, fmt};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PositionError {
+    /// `cells` does not hold `width * height` cells. Carries its length.
+    WRONG_LENGTH(usize),
+    /// No line of this many cells fits on the board.
+    INVALID_WIN_LENGTH(usize),
+    /// One player has two or more marks more than the other.
+    BAD_PIECE_COUNT,
+    /// Both players have completed a line.
+    TWO_WINNERS,
+    /// The losing player moved after the game was won.
+    MOVE_AFTER_WIN,
+    /// The winner completed lines that no single last move could finish.
+    DISJOINT_WINNING_LINES,
+    /// The player asked to move has more marks than the opponent, or is `NON`.
+    WRONG_SIDE_TO_MOVE,
+}
+
+impl fmt::Display for PositionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PositionError::WRONG_LENGTH(length) => {
+                write!(
+                    f,
+                    "the board has {} cells, which does not match its size",
+                    length
+                )
+            }
+            PositionError::INVALID_WIN_LENGTH(win_length) => {
+                write!(f, "no line of {} cells fits on the board", win_length)
+            }
+            PositionError::BAD_PIECE_COUNT => {
+                write!(f, "one player has too many marks to have taken turns")
+            }
+            PositionError::TWO_WINNERS => write!(f, "both players have completed a line"),
+            PositionError::MOVE_AFTER_WIN => write!(f, "a move was played after the game was won"),
+            PositionError::DISJOINT_WINNING_LINES => {
+                write!(
+                    f,
+                    "the winning lines could not have been completed by one move"
+                )
+            }
+            PositionError::WRONG_SIDE_TO_MOVE => write!(f, "this player cannot be the one to move"),
+        }
+    }
+}
+
+impl error::Error for PositionError {}
+
+impl Board {
+    /// Runs the checks of `Board::new`: `cells` fills the board and a line
+    /// of `win_length` fits on it.
+    pub(crate) fn validate_shape(&self) -> Result<(), PositionError> {
+        if self.cells.len() != self.width * self.height {
+            return Err(PositionError::WRONG_LENGTH(self.cells.len()));
+        }
+        if self.win_length < 1 || self.win_length > self.width.max(self.height) {
+            return Err(PositionError::INVALID_WIN_LENGTH(self.win_length));
+        }
+        return Ok(());
+    }
+
+    /// Checks that this position can be reached by players taking turns from
+    /// an empty board and stopping once a line is complete. Returns the first
+    /// problem found otherwise.
+    pub fn validate(&self) -> Result<(), PositionError> {
+        self.validate_shape()?;
+
+        let player_1_count = self.count_of(CellType::PLAYER_1);
+        let player_2_count = self.count_of(CellType::PLAYER_2);
+        if player_1_count.abs_diff(player_2_count) > 1 {
+            return Err(PositionError::BAD_PIECE_COUNT);
+        }
+
+        let player_1_lines = self.completed_lines(CellType::PLAYER_1);
+        let player_2_lines = self.completed_lines(CellType::PLAYER_2);
+        let (winner_lines, winner_count, loser_count) =
+            match (player_1_lines.is_empty(), player_2_lines.is_empty()) {
+                (true, true) => return Ok(()),
+                (false, false) => return Err(PositionError::TWO_WINNERS),
+                (false, true) => (player_1_lines, player_1_count, player_2_count),
+                (true, false) => (player_2_lines, player_2_count, player_1_count),
+            };
+
+        // The winner made the last move, so the loser cannot be ahead.
+        if winner_count < loser_count {
+            return Err(PositionError::MOVE_AFTER_WIN);
+        }
+
+        // Every completed line must go through the cell played last.
+        let is_shared = |position: &usize| {
+            winner_lines
+                .iter()
+                .all(|line| line.positions().any(|other| other == *position))
+        };
+        if !winner_lines[0]
+            .positions()
+            .any(|position| is_shared(&position))
+        {
+            return Err(PositionError::DISJOINT_WINNING_LINES);
+        }
+
+        return Ok(());
+    }
+
+    /// Like `validate`, and also checks that `to_move` may play next.
+    pub fn validate_for(&self, to_move: CellType) -> Result<(), PositionError> {
+        self.validate()?;
+
+        if to_move == CellType::NON || self.count_of(to_move) > self.count_of(to_move.opponent()) {
+            return Err(PositionError::WRONG_SIDE_TO_MOVE);
+        }
+        return Ok(());
+    }
+
+    fn count_of(&self, cell_type: CellType) -> usize {
+        return self.iter().filter(|&cell| *cell == cell_type).count();
+    }
+
+    fn completed_lines(&self, cell_type: CellType) -> Vec<Line> {
+        return self
+            .lines()
+            .filter(|line| line.positions().all(|position| self[position] == cell_type))
+            .collect();
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod validation_tests {
+    use crate::board::{self, Board, CellType, Coord, PositionError};
+
+    struct ValidationTest {
+        board: &'static str,
+        expected_result: Result<(), PositionError>,
+    }
+
+    macro_rules! validation_table_tests {
+        ( $( $name:ident: $value:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let test_data: ValidationTest = $value;
+                let board: Board = test_data.board.parse().unwrap();
+
+                assert_eq!(board.validate(), test_data.expected_result);
+            }
+        )*
+        };
+    }
+
+    validation_table_tests! {
+        given_empty_board_then_accept: ValidationTest {
+            board: ".../.../...",
+            expected_result: Ok(())
+        },
+
+        given_player_2_moved_first_then_accept: ValidationTest {
+            board: ".O./.../...",
+            expected_result: Ok(())
+        },
+
+        given_won_game_then_accept: ValidationTest {
+            board: "XXX/OO./...",
+            expected_result: Ok(())
+        },
+
+        given_two_lines_through_last_move_then_accept: ValidationTest {
+            board: "XXX/OXO/XOO",
+            expected_result: Ok(())
+        },
+
+        given_one_player_two_marks_ahead_then_reject: ValidationTest {
+            board: "XX./.../...",
+            expected_result: Err(PositionError::BAD_PIECE_COUNT)
+        },
+
+        given_both_players_won_then_reject: ValidationTest {
+            board: "XXX/OOO/...",
+            expected_result: Err(PositionError::TWO_WINNERS)
+        },
+
+        given_loser_moved_after_win_then_reject: ValidationTest {
+            board: "XXX/OO./OO.",
+            expected_result: Err(PositionError::MOVE_AFTER_WIN)
+        },
+
+        given_two_parallel_winning_lines_then_reject: ValidationTest {
+            board: "XXX.XXX/OO.OO.O 3",
+            expected_result: Err(PositionError::DISJOINT_WINNING_LINES)
+        },
+
+        given_overlapping_lines_on_larger_board_then_accept: ValidationTest {
+            board: "XXXX/OO.O/O.../.... 3",
+            expected_result: Ok(())
+        },
+    }
+
+    #[test]
+    fn given_cells_not_matching_size_then_reject() {
+        let mut board = board::new().get_board_state();
+        board.cells.pop();
+
+        assert_eq!(board.validate(), Err(PositionError::WRONG_LENGTH(8)));
+    }
+
+    #[test]
+    fn given_win_length_longer_than_board_then_reject() {
+        let mut board = board::new().get_board_state();
+        board.win_length = 4;
+
+        assert_eq!(board.validate(), Err(PositionError::INVALID_WIN_LENGTH(4)));
+    }
+
+    #[test]
+    fn given_player_ahead_then_reject_as_side_to_move() {
+        let board = board::new()
+            .set_player(Coord { x: 1, y: 1 }, CellType::PLAYER_1)
+            .get_board_state();
+
+        assert_eq!(board.validate_for(CellType::PLAYER_2), Ok(()));
+        assert_eq!(
+            board.validate_for(CellType::PLAYER_1),
+            Err(PositionError::WRONG_SIDE_TO_MOVE)
+        );
+        assert_eq!(
+            board.validate_for(CellType::NON),
+            Err(PositionError::WRONG_SIDE_TO_MOVE)
+        );
+    }
+}
@@ -1,2 +1,4 @@
 pub mod analyzer;
-pub mod board;
\ No newline at end of file
+pub mod arena;
+pub mod board;
+mod random;
@@ -0,0 +1,38 @@
+mod random_tests;
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Small seeded pseudo random generator (SplitMix64). Good enough to pick
+/// moves, and the same seed always gives the same sequence.
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Random {
+        return Random { state: seed };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        return value ^ (value >> 31);
+    }
+
+    /// A value in `0..bound`. `bound` must not be zero.
+    pub fn next_below(&mut self, bound: usize) -> usize {
+        return (self.next_u64() % bound as u64) as usize;
+    }
+}
+
+/// A seed that differs from run to run, for when reproducibility is not
+/// needed.
+pub fn seed_from_time() -> u64 {
+    return SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos() as u64)
+        .unwrap_or(0);
+}
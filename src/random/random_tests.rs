@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod random_tests {
+    use crate::random::Random;
+
+    #[test]
+    fn should_repeat_sequence_for_same_seed() {
+        let mut first = Random::new(42);
+        let mut second = Random::new(42);
+
+        for _ in 0..100 {
+            assert_eq!(first.next_u64(), second.next_u64());
+        }
+        assert_ne!(Random::new(1).next_u64(), Random::new(2).next_u64());
+    }
+
+    #[test]
+    fn should_stay_within_bounds() {
+        let mut random = Random::new(7);
+
+        for _ in 0..1000 {
+            assert!(random.next_below(9) < 9);
+        }
+    }
+}